@@ -13,21 +13,14 @@
 #![deny(missing_docs)]
 #![deny(rustdoc::missing_crate_level_docs)]
 
-use std::{
-	collections::HashMap,
-	path::{
-		//Path,
-		PathBuf
-	}
-};
-
-use lazy_static::lazy_static;
-use regex::Regex;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
 
-lazy_static! {
-	static ref CMD_CD: Regex = Regex::new(r"^cd (.*)$").unwrap();
-	static ref OUT_LS: Regex = Regex::new(r"^(\d+) ([\w.]+)$").unwrap();
-}
+/// The total space on the device
+const DISK_SPACE: usize = 70_000_000;
+/// The free space needed by the update
+const UPDATE_SPACE: usize = 30_000_000;
 
 /// Solve Advent of Code day 07 part one
 ///
@@ -47,61 +40,329 @@ lazy_static! {
 /// If any conversion assumed to be valid with the input fails, panics.
 #[must_use]
 pub fn solve_part_one(data: &str) -> usize {
-	let dtree = parse_input(data);
-	dtree.values()
-		.filter(|&v| *v <= 100_000)
-		.sum()
+	FsTree::from_str(data).expect("Failed").small_dirs_total(100_000)
 }
 
-/// Parse the day's input into a map of known directories and sizes
-///
-/// ### Arguments
-/// 
-///  - `data`: the input
-///
-/// ### Return Value
+/// The identifier of a node of a [`FsTree`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct NodeId(usize);
+
+/// What a node of a [`FsTree`] is
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum NodeKind {
+	/// A directory, with its entries sorted by name
+	Dir(BTreeMap<String, NodeId>),
+	/// A file, with its size
+	File(usize),
+}
+
+/// A directory or a file of a [`FsTree`]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Node {
+	name: String,
+	parent: Option<NodeId>,
+	kind: NodeKind,
+}
+
+impl Node {
+	/// The name of the node, `/` for the root
+	#[must_use]
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// The directory holding the node, or `None` for the root
+	#[must_use]
+	pub const fn parent(&self) -> Option<NodeId> {
+		self.parent
+	}
+
+	/// What the node is
+	#[must_use]
+	pub const fn kind(&self) -> &NodeKind {
+		&self.kind
+	}
+
+	/// Whether the node is a directory
+	#[must_use]
+	pub const fn is_dir(&self) -> bool {
+		matches!(self.kind, NodeKind::Dir(_))
+	}
+}
+
+/// The filesystem explored by a terminal transcript
 ///
-/// The built dictionary of directories, subdirectories, and all, up to root,
-/// with sizes as the value
-fn parse_input(data: &str) -> HashMap<String, usize> {
-	let mut dtree: HashMap<String, usize> = HashMap::new();
-	let mut cwd = PathBuf::from("/");
-	for cmdout in data.trim().split("$ ") {
-		//println!("CMDOUT: {}", cmdout);
-		let first_line = cmdout.split('\n').next().unwrap();
-		if let Some(v) = CMD_CD.captures(first_line) {
-			//println!("{:?}", v);
-			let dir = &v[1];
-			if dir.starts_with('/') {
-				// Absolute dir
-				//println!("Going to absolute {}", dir);
-				cwd = PathBuf::from(dir);
-			} else if dir == ".." {
-				//println!("Going up");
-				cwd.pop();
-			} else if dir == "." {
-				//println!("Staying");
-			} else {
-				//println!("Going to {}", dir);
-				cwd.push(dir);
+/// Nodes are stored in creation order, so that a node always comes after the
+/// directory holding it. Listing a directory several times only adds the
+/// entries it did not have yet, and the total size of every node is computed
+/// once, when the transcript has been read.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FsTree {
+	nodes: Vec<Node>,
+	sizes: Vec<usize>,
+}
+
+impl FsTree {
+	/// The identifier of the root directory
+	#[must_use]
+	pub const fn root(&self) -> NodeId {
+		NodeId(0)
+	}
+
+	/// The node with a given identifier
+	///
+	/// # Panics
+	///
+	/// Panics if the identifier comes from another tree.
+	#[must_use]
+	pub fn node(&self, id: NodeId) -> &Node {
+		&self.nodes[id.0]
+	}
+
+	/// How many directories and files the tree holds, the root left aside
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.nodes.len() - 1
+	}
+
+	/// Whether the root directory is empty
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// The size of a file, or the total size of the files within a directory
+	#[must_use]
+	pub fn size(&self, id: NodeId) -> usize {
+		self.sizes[id.0]
+	}
+
+	/// The entries of a directory, sorted by name, or nothing for a file
+	pub fn children(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+		let entries = match &self.nodes[id.0].kind {
+			NodeKind::Dir(entries) => Some(entries.values().copied()),
+			NodeKind::File(_) => None,
+		};
+		entries.into_iter().flatten()
+	}
+
+	/// The absolute path of a node
+	#[must_use]
+	pub fn path(&self, id: NodeId) -> String {
+		let mut names = Vec::new();
+		let mut current = id;
+		while let Some(parent) = self.nodes[current.0].parent {
+			names.push(self.nodes[current.0].name.as_str());
+			current = parent;
+		}
+		names.reverse();
+		format!("/{}", names.join("/"))
+	}
+
+	/// Find a node from its absolute path
+	#[must_use]
+	pub fn lookup(&self, path: &str) -> Option<NodeId> {
+		path.split('/')
+			.filter(|part| !part.is_empty())
+			.try_fold(self.root(), |current, part|
+				match &self.nodes[current.0].kind {
+					NodeKind::Dir(entries) => entries.get(part).copied(),
+					NodeKind::File(_) => None,
+				})
+	}
+
+	/// Walk depth first through a node and everything below it, every
+	/// directory coming before its entries
+	#[must_use]
+	pub fn walk(&self, from: NodeId) -> Walk<'_> {
+		Walk { tree: self, stack: vec![from] }
+	}
+
+	/// Walk depth first through the whole tree, see [`FsTree::walk`]
+	#[must_use]
+	pub fn iter(&self) -> Walk<'_> {
+		self.walk(self.root())
+	}
+
+	/// Every directory of the tree, depth first
+	pub fn dirs(&self) -> impl Iterator<Item = NodeId> + '_ {
+		self.iter().filter(|&id| self.nodes[id.0].is_dir())
+	}
+
+	/// Every file of the tree, depth first
+	pub fn files(&self) -> impl Iterator<Item = NodeId> + '_ {
+		self.iter().filter(|&id| !self.nodes[id.0].is_dir())
+	}
+
+	/// The sum of the sizes of the directories of at most `limit` bytes
+	#[must_use]
+	pub fn small_dirs_total(&self, limit: usize) -> usize {
+		self.dirs()
+			.map(|id| self.size(id))
+			.filter(|&size| size <= limit)
+			.sum()
+	}
+
+	/// The smallest directory to delete so that at least `needed` bytes are
+	/// free on a disk of `total` bytes, if there is one
+	#[must_use]
+	pub fn dir_to_delete(&self, total: usize, needed: usize) -> Option<NodeId> {
+		let free = total.saturating_sub(self.size(self.root()));
+		let missing = needed.saturating_sub(free);
+		self.dirs()
+			.filter(|&id| self.size(id) >= missing)
+			.min_by_key(|&id| self.size(id))
+	}
+
+	/// Add an entry to a directory, or return the one with the same name if
+	/// it is the same kind of node
+	fn add(&mut self, parent: NodeId, name: &str, kind: NodeKind)
+		-> Result<NodeId, String> {
+		if name.is_empty() || name.contains('/') || name == "." || name == ".." {
+			return Err(format!("Invalid name \"{name}\""));
+		}
+		let NodeKind::Dir(entries) = &self.nodes[parent.0].kind else {
+			return Err(format!("{} is not a directory", self.path(parent)));
+		};
+		if let Some(&existing) = entries.get(name) {
+			return match (&self.nodes[existing.0].kind, &kind) {
+				(NodeKind::Dir(_), NodeKind::Dir(_)) => Ok(existing),
+				(NodeKind::File(a), NodeKind::File(b)) if a == b => Ok(existing),
+				_ => Err(format!("{} was listed differently before",
+					self.path(existing))),
+			};
+		}
+		let id = NodeId(self.nodes.len());
+		if let NodeKind::Dir(entries) = &mut self.nodes[parent.0].kind {
+			entries.insert(name.to_string(), id);
+		}
+		self.nodes.push(Node {
+			name: name.to_string(),
+			parent: Some(parent),
+			kind,
+		});
+		Ok(id)
+	}
+
+	/// Change directory from `cwd`, one part of the path at a time, a path
+	/// starting with `/` being taken from the root
+	///
+	/// Entering a directory also tells it exists, so missing ones are added.
+	fn enter(&mut self, cwd: NodeId, path: &str) -> Result<NodeId, String> {
+		let start = if path.starts_with('/') { self.root() } else { cwd };
+		path.split('/')
+			.filter(|part| !part.is_empty())
+			.try_fold(start, |current, part| match part {
+				".." => Ok(self.nodes[current.0].parent.unwrap_or(current)),
+				"." => Ok(current),
+				_ => self.add(current, part, NodeKind::Dir(BTreeMap::new())),
+			})
+	}
+
+	fn compute_sizes(&mut self) {
+		self.sizes = self.nodes.iter()
+			.map(|node| match node.kind {
+				NodeKind::File(size) => size,
+				NodeKind::Dir(_) => 0,
+			})
+			.collect();
+		// Entries always come after their directory
+		for idx in (1..self.nodes.len()).rev() {
+			if let Some(parent) = self.nodes[idx].parent {
+				self.sizes[parent.0] += self.sizes[idx];
 			}
-		} else if first_line == "ls" {
-			// The first line has nothing useful
-			for output_line in cmdout.split('\n').skip(1) {
-				if let Some(c) = OUT_LS.captures(output_line) {
-					let size: usize = c[1].parse().unwrap();
-					let mut pclone = cwd.clone();
-					loop {
-						dtree.entry(pclone.to_str().unwrap().into())
-							.and_modify(|v| *v += size)
-							.or_insert(size);
-						if !pclone.pop() { break; }
+		}
+	}
+}
+
+impl FromStr for FsTree {
+	type Err = String;
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		let mut tree = Self {
+			nodes: vec![Node {
+				name: String::from("/"),
+				parent: None,
+				kind: NodeKind::Dir(BTreeMap::new()),
+			}],
+			sizes: Vec::new(),
+		};
+		let mut cwd = tree.root();
+		let mut listing = false;
+		for (idx, line) in data.lines().enumerate() {
+			let error = |message: String| format!("Line {}: {message}", idx + 1);
+			if let Some(command) = line.strip_prefix("$ ") {
+				listing = command == "ls";
+				let Some(dir) = command.strip_prefix("cd ") else {
+					if listing {
+						continue;
 					}
-				}
+					return Err(error(format!("Unknown command \"{command}\"")));
+				};
+				cwd = tree.enter(cwd, dir).map_err(error)?;
+			} else if listing {
+				let (info, name) = line.split_once(' ')
+					.ok_or_else(|| error(format!("Invalid entry \"{line}\"")))?;
+				let kind = if info == "dir" {
+					NodeKind::Dir(BTreeMap::new())
+				} else {
+					NodeKind::File(info.parse()
+						.map_err(|_| error(format!("Invalid size \"{info}\"")))?)
+				};
+				tree.add(cwd, name, kind).map_err(error)?;
+			} else if !line.trim().is_empty() {
+				return Err(error(String::from("Output outside of a listing")));
+			}
+		}
+		tree.compute_sizes();
+		Ok(tree)
+	}
+}
+
+impl fmt::Display for FsTree {
+	/// Draw the tree the way the puzzle does
+	fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for id in self {
+			let mut depth = 0;
+			let mut current = id;
+			while let Some(parent) = self.nodes[current.0].parent {
+				depth += 1;
+				current = parent;
+			}
+			let node = &self.nodes[id.0];
+			write!(fmt, "{:indent$}- {} ", "", node.name, indent = 2 * depth)?;
+			match node.kind {
+				NodeKind::Dir(_) => writeln!(fmt, "(dir)")?,
+				NodeKind::File(size) => writeln!(fmt, "(file, size={size})")?,
 			}
 		}
+		Ok(())
+	}
+}
+
+impl<'a> IntoIterator for &'a FsTree {
+	type Item = NodeId;
+	type IntoIter = Walk<'a>;
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// A depth first walk through a [`FsTree`], see [`FsTree::walk`]
+#[derive(Debug, Clone)]
+pub struct Walk<'a> {
+	tree: &'a FsTree,
+	stack: Vec<NodeId>,
+}
+
+impl Iterator for Walk<'_> {
+	type Item = NodeId;
+	fn next(&mut self) -> Option<Self::Item> {
+		let id = self.stack.pop()?;
+		if let NodeKind::Dir(entries) = &self.tree.nodes[id.0].kind {
+			self.stack.extend(entries.values().rev());
+		}
+		Some(id)
 	}
-	dtree
 }
 
 /// Solve Advent of Code day 07 part two
@@ -119,13 +380,10 @@ fn parse_input(data: &str) -> HashMap<String, usize> {
 ///
 /// If any conversion assumed to be valid with the input fails, panics.
 #[must_use]
-#[allow(clippy::missing_const_for_fn)]
 pub fn solve_part_two(data: &str) -> usize {
-	let dtree = parse_input(data);
-	let root_size = dtree.get("/").unwrap();
-	*dtree.values()
-		.filter(|&v| root_size - *v < 40_000_000)
-		.min().unwrap()
+	let tree = FsTree::from_str(data).expect("Failed");
+	let dir = tree.dir_to_delete(DISK_SPACE, UPDATE_SPACE).expect("No directory");
+	tree.size(dir)
 }
 
 // vim: set tw=80:
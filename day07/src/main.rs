@@ -3,8 +3,7 @@
 
 extern crate common;
 use common::read_data;
-mod lib;
-use lib::{solve_part_one, solve_part_two};
+use day07::{solve_part_one, solve_part_two};
 
 #[doc(hidden)]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -22,4 +21,80 @@ mod test {
 
 	test!(day07_01_example1, 1, 95437, "$ cd /\n$ ls\ndir a\n14848514 b.txt\n8504156 c.dat\ndir d\n$ cd a\n$ ls\ndir e\n29116 f\n2557 g\n62596 h.lst\n$ cd e\n$ ls\n584 i\n$ cd ..\n$ cd ..\n$ cd d\n$ ls\n4060174 j\n8033020 d.log\n5626152 d.ext\n7214296 k");
 	test!(day07_02_example1, 2, 24933642, "$ cd /\n$ ls\ndir a\n14848514 b.txt\n8504156 c.dat\ndir d\n$ cd a\n$ ls\ndir e\n29116 f\n2557 g\n62596 h.lst\n$ cd e\n$ ls\n584 i\n$ cd ..\n$ cd ..\n$ cd d\n$ ls\n4060174 j\n8033020 d.log\n5626152 d.ext\n7214296 k");
+
+	const EXAMPLE: &str = "$ cd /\n$ ls\ndir a\n14848514 b.txt\n8504156 c.dat\ndir d\n$ cd a\n$ ls\ndir e\n29116 f\n2557 g\n62596 h.lst\n$ cd e\n$ ls\n584 i\n$ cd ..\n$ cd ..\n$ cd d\n$ ls\n4060174 j\n8033020 d.log\n5626152 d.ext\n7214296 k";
+
+	#[test]
+	fn day07_fs_tree() {
+		use day07::FsTree;
+		let tree = EXAMPLE.parse::<FsTree>().unwrap();
+		assert_eq!(tree.to_string(), "\
+- / (dir)
+  - a (dir)
+    - e (dir)
+      - i (file, size=584)
+    - f (file, size=29116)
+    - g (file, size=2557)
+    - h.lst (file, size=62596)
+  - b.txt (file, size=14848514)
+  - c.dat (file, size=8504156)
+  - d (dir)
+    - d.ext (file, size=5626152)
+    - d.log (file, size=8033020)
+    - j (file, size=4060174)
+    - k (file, size=7214296)
+");
+		assert_eq!(tree.len(), 13);
+		assert!(!tree.is_empty());
+		assert!("$ cd /\n$ ls".parse::<FsTree>().unwrap().is_empty());
+		let e = tree.lookup("/a/e").unwrap();
+		assert_eq!(tree.size(e), 584);
+		assert_eq!(tree.path(e), "/a/e");
+		assert_eq!(tree.size(tree.root()), 48_381_165);
+		let dirs = tree.dirs().map(|d| tree.path(d)).collect::<Vec<String>>();
+		assert_eq!(dirs, ["/", "/a", "/a/e", "/d"]);
+		assert_eq!(tree.files().count(), 10);
+		assert_eq!(tree.walk(tree.lookup("/a").unwrap()).count(), 6);
+		let d = tree.dir_to_delete(70_000_000, 30_000_000).unwrap();
+		assert_eq!(tree.path(d), "/d");
+		assert!(tree.lookup("/a/f/g").is_none());
+	}
+
+	#[test]
+	fn day07_repeated_listings() {
+		use day07::FsTree;
+		// Listing the root again, from another directory, adds nothing
+		let again = format!("{EXAMPLE}\n$ cd /\n$ ls\ndir a\n14848514 b.txt\n\
+			8504156 c.dat\ndir d");
+		let tree = again.parse::<FsTree>().unwrap();
+		assert_eq!(tree, EXAMPLE.parse::<FsTree>().unwrap());
+		assert_eq!(tree.small_dirs_total(100_000), 95437);
+
+		let changed = "$ cd /\n$ ls\n12 a\n$ ls\n13 a";
+		assert_eq!(changed.parse::<FsTree>().unwrap_err(),
+			"Line 5: /a was listed differently before");
+		assert!("$ cd /\n$ ls\n12 a\n$ cd a".parse::<FsTree>().is_err());
+		assert!("$ cd /\n$ rm -rf a".parse::<FsTree>().is_err());
+		assert!("$ cd /\n12 a".parse::<FsTree>().is_err());
+	}
+
+	#[test]
+	fn day07_absolute_paths() {
+		use day07::FsTree;
+		let data = "$ cd /\n$ ls\ndir a\n$ cd /a\n$ ls\n10 x\n$ cd /a/b/../b\n\
+			$ ls\n20 y\n$ cd /\n$ cd a/b\n$ ls\n20 y";
+		let tree = data.parse::<FsTree>().unwrap();
+		assert_eq!(tree.len(), 4);
+		assert_eq!(tree.lookup("/a/x").map(|id| tree.size(id)), Some(10));
+		assert_eq!(tree.lookup("/a/b/y").map(|id| tree.size(id)), Some(20));
+		assert_eq!(tree.size(tree.root()), 30);
+		assert_eq!(tree.to_string(), concat!("- / (dir)\n", "  - a (dir)\n",
+			"    - b (dir)\n", "      - y (file, size=20)\n",
+			"    - x (file, size=10)\n"));
+
+		assert_eq!("$ cd /\n$ ls\n10 a/x".parse::<FsTree>().unwrap_err(),
+			"Line 3: Invalid name \"a/x\"");
+		assert!("$ cd /\n$ ls\ndir ..".parse::<FsTree>().is_err());
+		assert!("$ cd /\n$ ls\n10 x\n$ cd /x/y".parse::<FsTree>().is_err());
+	}
 }
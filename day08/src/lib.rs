@@ -13,12 +13,10 @@
 #![deny(missing_docs)]
 #![deny(rustdoc::missing_crate_level_docs)]
 
-// Some stuff allowed today
-#![allow(clippy::cast_possible_wrap)]
-
-use std::collections::HashMap;
 use std::str::FromStr;
 
+pub mod report;
+
 /// Solve Advent of Code day 08 part one
 ///
 /// # Arguments
@@ -35,135 +33,216 @@ use std::str::FromStr;
 /// If any conversion assumed to be valid with the input fails, panics.
 #[must_use]
 pub fn solve_part_one(data: &str) -> usize {
-	let mut grid = Grid::from_str(data).expect("Failed");
-	let grid_width = grid.get_width();
-	let grid_height = grid.get_height();
-	for xpos in 1..grid_height-1 {
-		for ypos in 1..grid_width-1 {
-			let (my_height, _, _) = grid.at(ypos, xpos);
-			// In all four directions, go up/down/left/down until we find either
-			// a visible edge, a visible smaller than us, or a hidden
-			let mut seen = false;
-			let mut whence = 0;
-
-			for (ydiff, xdiff, xcode) in [(-1, 0, 0b0001), (0, -1, 0b0010), (1, 0, 0b0100), (0, 1, 0b1000)] {
-				let mut ny = ypos + ydiff;
-				let mut nx = xpos + xdiff;
-				loop {
-					let (height, status, visfrom) = grid.at(ny, nx);
-					if let Some(visible) = status {
-						if visfrom == xcode && visible && my_height > height{
-							// We're visible
-							seen = true;
-							whence = xcode;
-							break;
-						}
-					}
-					if height >= my_height {
-						// Break, we're not going to get seen from there
-						//println!("({}, {}) hit a wall for ({}, {})", ypos, xpos, ydiff, xdiff);
-						break;
-					}
-					if ny == 0 || nx == 0 || ny == grid_width-1 || nx == grid_height-1 {
-						//println!("({}, {}) hit a wall for ({}, {})", ypos, xpos, ydiff, xdiff);
-						seen = true;
-						whence = xcode;
-						break; 
-					}
-					ny += ydiff;
-					nx += xdiff;
-				}
-				if seen { grid.visible(ypos, xpos, whence); break; }
-			}
+	Forest::from_str(data).expect("Failed").count_visible()
+}
 
-			if !seen {
-				grid.hide(ypos, xpos);
-			}
-		}
+/// A direction to look in from a tree
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Direction {
+	/// Towards the first row
+	Up,
+	/// Towards the last row
+	Down,
+	/// Towards the first column
+	Left,
+	/// Towards the last column
+	Right,
+}
+
+impl Direction {
+	/// All directions, in the order used for per-direction values
+	pub const ALL: [Self; 4] = [Self::Up, Self::Down, Self::Left, Self::Right];
+
+	const fn index(self) -> usize {
+		self as usize
 	}
-	//println!("{}", grid);
-	grid.count_visible()
 }
 
-#[derive(Debug)]
-struct Grid {
-	grid: HashMap<(isize, isize), (usize, Option<bool>, usize)>,
-	width: isize,
-	height: isize
+/// A forest of trees on a rectangular grid, along with the visibility and
+/// viewing distances of every tree
+///
+/// Positions are `(x, y)` pairs, `x` being the column and `y` the row, both
+/// starting from the top left corner.
+///
+/// Everything is computed once when the forest is built, with a monotonic
+/// stack along every row and column in both directions, so that it takes time
+/// proportional to the number of trees.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Forest {
+	width: usize,
+	height: usize,
+	heights: Vec<u8>,
+	// For every tree, one bit per direction it is visible from
+	visible: Vec<u8>,
+	distances: Vec<[usize; 4]>,
 }
 
-impl Grid {
-	const fn get_width(&self) -> isize {
+impl Forest {
+	/// Build a forest from the heights of its trees, row after row
+	///
+	/// # Errors
+	///
+	/// Returns a `String` if the number of heights is not `width * height`.
+	pub fn new(width: usize, heights: Vec<u8>) -> Result<Self, String> {
+		if width == 0 || heights.is_empty() || !heights.len().is_multiple_of(width) {
+			return Err(format!("{} trees cannot fill rows of {width}", heights.len()));
+		}
+		let height = heights.len() / width;
+		let mut forest = Self {
+			width,
+			height,
+			visible: vec![0; heights.len()],
+			distances: vec![[0; 4]; heights.len()],
+			heights,
+		};
+
+		let mut stack = Vec::new();
+		for y in 0..height {
+			let row = y * width..(y + 1) * width;
+			forest.scan(row.clone(), Direction::Left, &mut stack);
+			forest.scan(row.rev(), Direction::Right, &mut stack);
+		}
+		for x in 0..width {
+			let column = (x..forest.heights.len()).step_by(width);
+			forest.scan(column.clone(), Direction::Up, &mut stack);
+			forest.scan(column.rev(), Direction::Down, &mut stack);
+		}
+		Ok(forest)
+	}
+
+	/// Walk along a line of trees, looking back towards `direction`
+	///
+	/// The stack holds the trees seen so far that are not hidden behind a
+	/// later tree at least as high, so its top is the nearest tree blocking
+	/// the view of the current one.
+	fn scan<I>(&mut self, cells: I, direction: Direction,
+			   stack: &mut Vec<(usize, u8)>)
+		where I: Iterator<Item = usize> {
+		stack.clear();
+		for (position, cell) in cells.enumerate() {
+			let height = self.heights[cell];
+			while stack.last().is_some_and(|&(_, h)| h < height) {
+				stack.pop();
+			}
+			self.distances[cell][direction.index()] =
+				if let Some(&(blocker, _)) = stack.last() {
+					position - blocker
+				} else {
+					self.visible[cell] |= 1 << direction.index();
+					position
+				};
+			stack.push((position, height));
+		}
+	}
+
+	fn cell(&self, (x, y): (usize, usize)) -> Option<usize> {
+		(x < self.width && y < self.height).then_some(y * self.width + x)
+	}
+
+	/// How many columns the forest has
+	#[must_use]
+	pub const fn width(&self) -> usize {
 		self.width
 	}
 
-	const fn get_height(&self) -> isize {
+	/// How many rows the forest has
+	#[must_use]
+	pub const fn height(&self) -> usize {
 		self.height
 	}
 
-	fn at(&self, y_pos: isize, x_pos: isize) -> (usize, Option<bool>, usize) {
-		self.grid[&(y_pos, x_pos)]
+	/// The height of the tree at a position, if there is one
+	#[must_use]
+	pub fn tree(&self, position: (usize, usize)) -> Option<u8> {
+		self.cell(position).map(|c| self.heights[c])
+	}
+
+	/// Whether the tree at a position can be seen from outside the forest
+	/// when looking from `direction`, if there is one
+	#[must_use]
+	pub fn visible_from(&self, position: (usize, usize), direction: Direction)
+		-> Option<bool> {
+		self.cell(position)
+			.map(|c| self.visible[c] & (1 << direction.index()) != 0)
+	}
+
+	/// Whether the tree at a position can be seen from outside the forest, if
+	/// there is one
+	#[must_use]
+	pub fn is_visible(&self, position: (usize, usize)) -> Option<bool> {
+		self.cell(position).map(|c| self.visible[c] != 0)
 	}
 
-	fn hide(&mut self, y_pos: isize, x_pos: isize) {
-		self.grid.entry((y_pos, x_pos))
-			.and_modify(|(_, status, _)| *status = Some(false) );
+	/// How many trees can be seen from the tree at a position, in every
+	/// direction, in the order of [`Direction::ALL`], if there is one
+	#[must_use]
+	pub fn viewing_distances(&self, position: (usize, usize))
+		-> Option<[usize; 4]> {
+		self.cell(position).map(|c| self.distances[c])
 	}
 
-	fn visible(&mut self, y_pos: isize, x_pos: isize, dir: usize) {
-		self.grid.entry((y_pos, x_pos))
-			.and_modify(|(_, status, mydir)| { *status = Some(true); *mydir = dir; } );
+	/// The scenic score of the tree at a position, the product of its viewing
+	/// distances, if there is one
+	#[must_use]
+	pub fn scenic_score(&self, position: (usize, usize)) -> Option<usize> {
+		self.viewing_distances(position).map(|d| d.iter().product())
 	}
 
-	fn count_visible(&self) -> usize {
-		self.grid.values()
-			.filter(|(_, v, _)| v.map_or(false, |k| k))
-			.count()
+	/// How many trees can be seen from outside the forest
+	#[must_use]
+	pub fn count_visible(&self) -> usize {
+		self.visible.iter().filter(|&&v| v != 0).count()
+	}
+
+	/// The highest scenic score of any tree
+	#[must_use]
+	pub fn max_scenic_score(&self) -> usize {
+		self.distances.iter()
+			.map(|d| d.iter().product())
+			.max()
+			.unwrap_or_default()
 	}
 }
 
-impl std::fmt::Display for Grid {
+impl std::fmt::Display for Forest {
+	/// Write the heights of the visible trees, hidden ones being blanks
 	fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		for ypos in 0..self.height {
-			for xpos in 0..self.width {
-				let (height, vis, _) = self.at(ypos, xpos);
-				if let Some(visible) = vis {
-					if visible {
-						write!(fmt, "{height}")?;
-					} else {
-						write!(fmt, " ")?;
-					}
+		for row in 0..self.height {
+			for cell in row * self.width..(row + 1) * self.width {
+				if self.visible[cell] == 0 {
+					write!(fmt, " ")?;
 				} else {
-					write!(fmt, ".")?;
+					write!(fmt, "{}", self.heights[cell])?;
 				}
 			}
 			writeln!(fmt)?;
-		};
+		}
 		Ok(())
 	}
 }
 
-impl FromStr for Grid {
-	type Err = ();
+impl FromStr for Forest {
+	type Err = String;
 	fn from_str(data: &str) -> Result<Self, Self::Err> {
-		let lines = data.trim().split('\n').collect::<Vec<&str>>();
-		let columns = lines[0].len();
-		let rows = lines.len();
-
-		let grid = lines.iter()
-			.enumerate()
-			.flat_map(|(u, v)| {
-				v.chars()
-					.enumerate()
-					.map(|(n, x)| {
-						let status = if u == 0 || n == 0 || u == rows-1 || n == columns-1 { Some(true) } else { None };
-						((u as isize, n as isize), (x.to_digit(10).unwrap() as usize, status, 0))
-					})
-					.collect::<Vec<((isize, isize), (usize, Option<bool>, usize))>>()
-			})
-			.collect::<HashMap<_, _>>();
-
-		Ok(Self { grid, width: columns as isize, height: rows as isize })
+		let lines = data.lines()
+			.map(str::trim)
+			.filter(|l| !l.is_empty())
+			.collect::<Vec<&str>>();
+		let width = lines.first().map_or(0, |l| l.len());
+		let mut heights = Vec::with_capacity(width * lines.len());
+		for (idx, line) in lines.iter().enumerate() {
+			if line.len() != width {
+				return Err(format!("Line {}: expected {width} trees, found {}",
+					idx + 1, line.len()));
+			}
+			for c in line.chars() {
+				let height = c.to_digit(10)
+					.and_then(|h| u8::try_from(h).ok())
+					.ok_or_else(|| format!("Line {}: invalid height '{c}'", idx + 1))?;
+				heights.push(height);
+			}
+		}
+		Self::new(width, heights)
 	}
 }
 
@@ -183,46 +262,7 @@ impl FromStr for Grid {
 /// If any conversion assumed to be valid with the input fails, panics.
 #[must_use]
 pub fn solve_part_two(data: &str) -> usize {
-	let grid = Grid::from_str(data).expect("Failed");
-	let grid_width = grid.get_width();
-	let grid_height = grid.get_height();
-
-	let mut scores: HashMap<(isize, isize), usize> = HashMap::new();
-
-	for ypos in 1..grid_height-1 {
-		for xpos in 1..grid_width-1 {
-			let (my_height, _, _) = grid.at(ypos, xpos);
-
-			let scenery_score = [
-					(-1, 0), (0, -1),
-					(0, 1), (1, 0)
-				].iter()
-				.map(|(ydiff, xdiff)| {
-					let mut ny = ypos + ydiff;
-					let mut nx = xpos + xdiff;
-					let mut count = 0;
-
-					while ny >= 0 && nx >= 0 && ny < grid_height && nx < grid_width {
-						// Count
-						let (height, _, _) = grid.at(ny, nx);
-						count += 1;
-						if height >= my_height {
-							break;
-						}
-
-						ny += ydiff;
-						nx += xdiff;
-					}
-					//println!("({}, {}) breaks ({}, {}) with {}",
-								//ypos, xpos, ydiff, xdiff, count);
-					count
-				})
-				.fold(1, |s, v| v * s);
-			scores.insert((ypos, xpos), scenery_score);
-		}
-	}
-
-	*scores.values().max().unwrap()
+	Forest::from_str(data).expect("Failed").max_scenic_score()
 }
 
 // vim: set tw=80:
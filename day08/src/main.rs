@@ -3,8 +3,7 @@
 
 extern crate common;
 use common::read_data;
-mod lib;
-use lib::{solve_part_one, solve_part_two};
+use day08::{solve_part_one, solve_part_two};
 
 #[doc(hidden)]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -22,4 +21,68 @@ mod test {
 
 	test!(day08_01_example1, 1, 21, "30373\n25512\n65332\n33549\n35390");
 	test!(day08_02_example1, 2, 8, "30373\n25512\n65332\n33549\n35390");
+
+	#[test]
+	fn day08_forest() {
+		use day08::{Direction, Forest};
+		let forest = "30373\n25512\n65332\n33549\n35390".parse::<Forest>().unwrap();
+		assert_eq!(forest.to_string(), "30373\n255 2\n65 32\n3 5 9\n35390\n");
+		assert_eq!(forest.viewing_distances((2, 1)), Some([1, 2, 1, 2]));
+		assert_eq!(forest.scenic_score((2, 3)), Some(8));
+		assert_eq!(forest.visible_from((1, 1), Direction::Up), Some(true));
+		assert_eq!(forest.visible_from((1, 1), Direction::Right), Some(false));
+		assert_eq!(forest.is_visible((5, 0)), None);
+
+		// Rectangular forests work in both orientations
+		let wide = "3037\n2551\n6533".parse::<Forest>().unwrap();
+		assert_eq!((wide.width(), wide.height()), (4, 3));
+		assert_eq!(wide.count_visible(), 12);
+		assert_eq!(wide.viewing_distances((1, 1)), Some([1, 1, 1, 1]));
+		let tall = "303\n255\n653\n335\n353".parse::<Forest>().unwrap();
+		assert_eq!(tall.count_visible(), 14);
+		assert_eq!(tall.max_scenic_score(), 2);
+
+		assert!("123\n12".parse::<Forest>().unwrap_err().starts_with("Line 2"));
+		assert!("1a3".parse::<Forest>().is_err());
+	}
+
+	#[test]
+	fn day08_report() {
+		use day08::Forest;
+		use day08::report::{heat_map, report, Format, HeatMap};
+		let forest = "30373\n25512\n65332\n33549\n35390".parse::<Forest>().unwrap();
+		let spots = forest.top_scenic(2);
+		assert_eq!(spots[0].position, (2, 3));
+		assert_eq!(spots[0].distances, [2, 1, 2, 2]);
+		assert_eq!(spots[1].position, (1, 2));
+		assert_eq!(report(&forest, 2), "\
+5x5 forest, 21 trees visible from outside:
+30373
+255 2
+65 32
+3 5 9
+35390
+
+Top 2 scenic spots:
+1. (2, 3) height 5: score 8 (up 2, down 1, left 2, right 2)
+2. (1, 2) height 5: score 6 (up 1, down 2, left 1, right 3)
+");
+		assert_eq!(heat_map(&forest, HeatMap::Visibility, Format::Csv), "\
+2,1,1,3,2
+1,2,2,0,1
+4,1,0,1,1
+1,0,2,0,4
+2,2,1,4,2
+");
+		assert_eq!(heat_map(&forest, HeatMap::Scenic, Format::Pgm), "\
+P2
+5 5
+8
+0 0 0 0 0
+0 1 4 1 0
+0 6 1 2 0
+0 1 8 3 0
+0 0 0 0 0
+");
+	}
 }
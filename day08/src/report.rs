@@ -0,0 +1,158 @@
+//! Reports on where to build the tree house, and heat maps of the forest
+//!
+//! The report lists the best scenic spots of a [`Forest`] along with how far
+//! one sees from them in every direction, after the map of the visible trees
+//! drawn by its `Display` implementation. Heat maps give one value per tree,
+//! and are written either as a plain PGM picture or as CSV.
+
+use std::fmt::{self, Write};
+
+use super::{Direction, Forest};
+
+/// The largest value of a plain PGM picture
+const PGM_MAX: usize = 65535;
+
+/// A tree along with how far one sees from it
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Spot {
+	/// The position of the tree, as `(x, y)`
+	pub position: (usize, usize),
+	/// The height of the tree
+	pub height: u8,
+	/// The viewing distances, in the order of [`Direction::ALL`]
+	pub distances: [usize; 4],
+	/// The scenic score, the product of the viewing distances
+	pub score: usize,
+}
+
+impl Spot {
+	/// The viewing distance towards one direction
+	#[must_use]
+	pub const fn distance(&self, direction: Direction) -> usize {
+		self.distances[direction.index()]
+	}
+}
+
+impl fmt::Display for Spot {
+	fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (x, y) = self.position;
+		write!(fmt, "({x}, {y}) height {}: score {} (up {}, down {}, left {}, \
+			right {})", self.height, self.score,
+			self.distance(Direction::Up), self.distance(Direction::Down),
+			self.distance(Direction::Left), self.distance(Direction::Right))
+	}
+}
+
+impl Forest {
+	/// The `k` trees with the highest scenic scores, best first
+	///
+	/// Trees with the same score are listed row after row, then column after
+	/// column.
+	#[must_use]
+	pub fn top_scenic(&self, k: usize) -> Vec<Spot> {
+		let mut spots = (0..self.heights.len())
+			.map(|cell| {
+				let distances = self.distances[cell];
+				Spot {
+					position: (cell % self.width, cell / self.width),
+					height: self.heights[cell],
+					distances,
+					score: distances.iter().product(),
+				}
+			})
+			.collect::<Vec<Spot>>();
+		spots.sort_by(|a, b| b.score.cmp(&a.score)
+			.then((a.position.1, a.position.0).cmp(&(b.position.1, b.position.0))));
+		spots.truncate(k);
+		spots
+	}
+}
+
+/// What a heat map shows for every tree
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum HeatMap {
+	/// From how many directions, up to four, the tree can be seen from
+	/// outside the forest
+	Visibility,
+	/// The scenic score of the tree
+	Scenic,
+}
+
+/// How a heat map is written
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Format {
+	/// A plain PGM picture, values being scaled down if they do not fit
+	Pgm,
+	/// One line of comma separated values per row
+	Csv,
+}
+
+/// Write a heat map of a forest
+///
+/// # Arguments
+///
+///  - `forest` : the [`Forest`] to map
+///  - `map` : what the map shows, see [`HeatMap`]
+///  - `format` : how the map is written, see [`Format`]
+#[must_use]
+pub fn heat_map(forest: &Forest, map: HeatMap, format: Format) -> String {
+	let values = match map {
+		HeatMap::Visibility => forest.visible.iter()
+			.map(|v| v.count_ones() as usize)
+			.collect::<Vec<usize>>(),
+		HeatMap::Scenic => forest.distances.iter()
+			.map(|d| d.iter().product())
+			.collect(),
+	};
+
+	let max = match (format, map) {
+		(Format::Csv, _) => 1,
+		(Format::Pgm, HeatMap::Visibility) => Direction::ALL.len(),
+		(Format::Pgm, HeatMap::Scenic) => values.iter().copied()
+			.max()
+			.unwrap_or_default()
+			.max(1),
+	};
+	let scale = max.div_ceil(PGM_MAX);
+	let mut out = String::new();
+	let separator = match format {
+		Format::Pgm => {
+			// Writing to a String never fails
+			let _ = writeln!(out, "P2\n{} {}\n{}", forest.width, forest.height,
+				max / scale);
+			' '
+		},
+		Format::Csv => ',',
+	};
+	for row in values.chunks(forest.width) {
+		for (idx, &value) in row.iter().enumerate() {
+			if idx > 0 {
+				out.push(separator);
+			}
+			let _ = write!(out, "{}", value / scale);
+		}
+		out.push('\n');
+	}
+	out
+}
+
+/// Report on the visible trees and the best spots for a tree house
+///
+/// # Arguments
+///
+///  - `forest` : the [`Forest`] to report on
+///  - `k` : how many of the best scenic spots are listed
+#[must_use]
+pub fn report(forest: &Forest, k: usize) -> String {
+	let mut out = String::new();
+	// Writing to a String never fails
+	let _ = writeln!(out, "{}x{} forest, {} trees visible from outside:",
+		forest.width, forest.height, forest.count_visible());
+	let _ = write!(out, "{forest}");
+	let spots = forest.top_scenic(k);
+	let _ = writeln!(out, "\nTop {} scenic spots:", spots.len());
+	for (rank, spot) in spots.iter().enumerate() {
+		let _ = writeln!(out, "{}. {spot}", rank + 1);
+	}
+	out
+}
@@ -0,0 +1,204 @@
+//! Counting visited positions for long ropes and long motion logs
+//!
+//! Instead of moving every knot after every step, knots are simulated one
+//! after the other : the path of a knot only holds the positions it moved to,
+//! and the path of the next knot is computed from it, since a knot only moves
+//! when the knot before it did. Paths get shorter down the rope, and once a
+//! knot never moves, none of the knots after it do either.
+//!
+//! Visited positions are marked in a bitmap covering the area the head goes
+//! through, which no other knot leaves unless the whole rope jumps. When that
+//! area is too large, as with long diagonals or far teleports, positions are
+//! marked in a sparse bitmap instead, made of small tiles that are only
+//! allocated once the rope goes through them.
+
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use super::{follow, Motion};
+
+/// The largest flat bitmap that is allocated, in bits
+const MAX_AREA: usize = 1 << 28;
+
+/// The width and height of a tile of a sparse bitmap, one bit per position
+const TILE: isize = 8;
+
+/// A set of positions
+enum Bitmap {
+	/// One bit per position of a fixed area
+	Flat {
+		min_x: isize,
+		min_y: isize,
+		width: usize,
+		height: usize,
+		bits: Vec<u64>,
+	},
+	/// Tiles of `TILE` by `TILE` positions, allocated when first marked
+	Sparse(HashMap<(isize, isize), u64>),
+}
+
+impl Bitmap {
+	/// A bitmap for the area of a path, flat unless it is too large
+	fn new(xs: &RangeInclusive<isize>, ys: &RangeInclusive<isize>) -> Self {
+		let width = xs.end().abs_diff(*xs.start()) + 1;
+		let height = ys.end().abs_diff(*ys.start()) + 1;
+		width.checked_mul(height)
+			.filter(|&area| area <= MAX_AREA)
+			.map_or_else(|| Self::Sparse(HashMap::new()), |area| Self::Flat {
+				min_x: *xs.start(),
+				min_y: *ys.start(),
+				width,
+				height,
+				bits: vec![0; area.div_ceil(64)],
+			})
+	}
+
+	/// Whether positions within an area can be marked
+	fn covers(&self, xs: &RangeInclusive<isize>, ys: &RangeInclusive<isize>)
+		-> bool {
+		match self {
+			Self::Flat { min_x, min_y, width, height, .. } =>
+				xs.start() >= min_x && ys.start() >= min_y
+					&& xs.end().abs_diff(*min_x) < *width
+					&& ys.end().abs_diff(*min_y) < *height,
+			Self::Sparse(_) => true,
+		}
+	}
+
+	/// The word holding a position, and the bit of the position within it
+	fn index(&mut self, (x, y): (isize, isize)) -> (&mut u64, u64) {
+		match self {
+			Self::Flat { min_x, min_y, width, bits, .. } => {
+				let idx = y.abs_diff(*min_y) * *width + x.abs_diff(*min_x);
+				(&mut bits[idx / 64], 1 << (idx % 64))
+			},
+			Self::Sparse(tiles) => (
+				tiles.entry((x.div_euclid(TILE), y.div_euclid(TILE)))
+					.or_default(),
+				1 << (y.rem_euclid(TILE) * TILE + x.rem_euclid(TILE)),
+			),
+		}
+	}
+
+	/// Mark a position, returning whether it was not marked yet
+	fn insert(&mut self, position: (isize, isize)) -> bool {
+		let (word, bit) = self.index(position);
+		let new = *word & bit == 0;
+		*word |= bit;
+		new
+	}
+
+	/// Unmark every position of a path, keeping the memory around
+	fn clear(&mut self, path: &[PathStep]) {
+		match self {
+			// Clearing only what was set is cheaper than clearing the bitmap
+			Self::Flat { .. } => for &(p, _) in path {
+				let (word, bit) = self.index(p);
+				*word &= !bit;
+			},
+			Self::Sparse(tiles) => tiles.clear(),
+		}
+	}
+}
+
+/// A position a knot moves to, and whether the whole rope jumped there
+type PathStep = ((isize, isize), bool);
+
+/// Every position the head goes through, starting from `(0, 0)`
+fn head_path(motions: &[Motion]) -> Vec<PathStep> {
+	let total = motions.iter()
+		.map(|m| match m {
+			Motion::Steps { count, .. } => *count,
+			_ => 1,
+		})
+		.sum::<usize>();
+	let mut path = Vec::with_capacity(total + 1);
+	let mut head = (0, 0);
+	path.push((head, false));
+	for motion in motions {
+		match *motion {
+			Motion::Steps { direction, count } => {
+				let (dx, dy) = direction.offset();
+				for _ in 0..count {
+					head = (head.0 + dx, head.1 + dy);
+					path.push((head, false));
+				}
+			},
+			Motion::Jump(dx, dy) => {
+				head = (head.0 + dx, head.1 + dy);
+				path.push((head, true));
+			},
+			Motion::Teleport(x, y) => {
+				head = (x, y);
+				path.push((head, true));
+			},
+		}
+	}
+	path
+}
+
+/// The path of the knot following a knot with the given path
+fn follower_path(path: &[PathStep], next: &mut Vec<PathStep>) {
+	next.clear();
+	let mut tail = (0, 0);
+	next.push((tail, false));
+	for pair in path.windows(2) {
+		let ((before, _), (head, jumped)) = (pair[0], pair[1]);
+		let moved = if jumped {
+			// The whole rope keeps its shape
+			(tail.0 + head.0 - before.0, tail.1 + head.1 - before.1)
+		} else {
+			follow(head, tail)
+		};
+		if moved != tail {
+			tail = moved;
+			next.push((tail, jumped));
+		}
+	}
+}
+
+/// The smallest area holding a path, as ranges of `x` and `y`
+fn bounds(path: &[PathStep]) -> (RangeInclusive<isize>, RangeInclusive<isize>) {
+	let (min_x, max_x, min_y, max_y) = path.iter()
+		.fold((0, 0, 0, 0), |(a, b, c, d), &((x, y), _)|
+			(a.min(x), b.max(x), c.min(y), d.max(y)));
+	(min_x..=max_x, min_y..=max_y)
+}
+
+/// Count how many positions every knot of a rope visits at least once
+///
+/// # Arguments
+///
+///  - `motions` : the motions of the rope
+///  - `knots` : how many knots the rope has, the head included
+///
+/// # Return value
+///
+/// Returns the number of visited positions of every knot, head first.
+#[must_use]
+pub fn visited_counts(motions: &[Motion], knots: usize) -> Vec<usize> {
+	let mut path = head_path(motions);
+	let (xs, ys) = bounds(&path);
+	let mut bitmap = Bitmap::new(&xs, &ys);
+
+	let mut counts = Vec::with_capacity(knots);
+	let mut next = Vec::with_capacity(path.len());
+	while counts.len() < knots {
+		// A knot that never moves visits its start, and so do all the next
+		if path.len() == 1 {
+			counts.resize(knots, 1);
+			break;
+		}
+		// Knots only leave the area of the head when the rope jumps
+		let (xs, ys) = bounds(&path);
+		if !bitmap.covers(&xs, &ys) {
+			bitmap = Bitmap::new(&xs, &ys);
+		}
+		counts.push(path.iter().filter(|&&(p, _)| bitmap.insert(p)).count());
+		bitmap.clear(&path);
+
+		follower_path(&path, &mut next);
+		std::mem::swap(&mut path, &mut next);
+	}
+	counts
+}
@@ -13,7 +13,12 @@
 #![deny(missing_docs)]
 #![deny(rustdoc::missing_crate_level_docs)]
 
-use std::collections::HashSet;
+use std::{
+	collections::HashSet,
+	ops::RangeInclusive,
+};
+
+pub mod dense;
 
 /// Solve Advent of Code day 09 part one
 ///
@@ -30,105 +35,374 @@ use std::collections::HashSet;
 ///
 /// If any conversion assumed to be valid with the input fails, panics.
 #[must_use]
-#[allow(clippy::missing_const_for_fn)]
 pub fn solve_part_one(data: &str) -> usize {
-	let mut model = RopeModel::new(1);
-	data.split('\n')
-		.map(|x| {
-			let mut divs = x.split(' ');
-			let mv = divs.next().unwrap().parse::<Move>().unwrap();
-			let count = divs.next().unwrap().parse::<usize>().unwrap();
-			(mv, count)
-		})
-		.for_each(|(mv, count)| model.apply_all(mv, count));
-	model.tail_positions().iter().count()
+	let mut rope = Rope::new(2);
+	rope.run(&parse_motions(data).unwrap());
+	rope.visited(1).len()
 }
 
-#[derive(Debug, Copy, Clone)]
-enum Move {
+/// A direction the head of the rope moves in
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Move {
+	/// Towards positive `y`
 	Up,
+	/// Towards negative `y`
 	Down,
+	/// Towards negative `x`
 	Left,
-	Right
+	/// Towards positive `x`
+	Right,
+	/// Diagonally up and left
+	UpLeft,
+	/// Diagonally up and right
+	UpRight,
+	/// Diagonally down and left
+	DownLeft,
+	/// Diagonally down and right
+	DownRight,
 }
 
+impl Move {
+	/// The offset of a single step in this direction
+	#[must_use]
+	pub const fn offset(self) -> (isize, isize) {
+		match self {
+			Self::Up => (0, 1),
+			Self::Down => (0, -1),
+			Self::Left => (-1, 0),
+			Self::Right => (1, 0),
+			Self::UpLeft => (-1, 1),
+			Self::UpRight => (1, 1),
+			Self::DownLeft => (-1, -1),
+			Self::DownRight => (1, -1),
+		}
+	}
+}
+
+/// The error returned when a motion cannot be parsed, naming the token at
+/// fault
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseMotionError {
+	token: String,
+	message: &'static str,
+}
+
+impl ParseMotionError {
+	fn new(token: &str, message: &'static str) -> Self {
+		Self { token: token.to_string(), message }
+	}
+
+	/// The token that could not be parsed, empty if one was missing
+	#[must_use]
+	pub fn token(&self) -> &str {
+		&self.token
+	}
+}
+
+impl std::fmt::Display for ParseMotionError {
+	fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(fmt, "{} \"{}\"", self.message, self.token)
+	}
+}
+
+impl std::error::Error for ParseMotionError {}
+
 impl std::str::FromStr for Move {
-	type Err = ();
+	type Err = ParseMotionError;
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		if s == "U" {
-			Ok(Self::Up)
-		} else if s == "D" {
-			Ok(Self::Down)
-		} else if s == "L" {
-			Ok(Self::Left)
-		} else if s == "R" {
-			Ok(Self::Right)
-		} else {
-			Err(())
+		match s {
+			"U" => Ok(Self::Up),
+			"D" => Ok(Self::Down),
+			"L" => Ok(Self::Left),
+			"R" => Ok(Self::Right),
+			"UL" => Ok(Self::UpLeft),
+			"UR" => Ok(Self::UpRight),
+			"DL" => Ok(Self::DownLeft),
+			"DR" => Ok(Self::DownRight),
+			_ => Err(ParseMotionError::new(s, "Unknown direction")),
 		}
 	}
 }
 
-struct RopeModel {
-	//head: (isize, isize),
-	//tail: (isize, isize),
+/// A motion of the rope
+///
+/// Steps move the head one cell at a time, the other knots following it,
+/// while jumps and teleports move the whole rope at once, keeping its shape.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Motion {
+	/// The head takes steps in a direction, written like `R 4` or `UL 2`
+	Steps {
+		/// The direction of every step
+		direction: Move,
+		/// How many steps are taken
+		count: usize,
+	},
+	/// The whole rope moves by an offset, written `J dx dy`
+	Jump(isize, isize),
+	/// The whole rope moves so that the head lands on a position, written
+	/// `T x y`
+	Teleport(isize, isize),
+}
+
+impl std::str::FromStr for Motion {
+	type Err = ParseMotionError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut tokens = s.split_whitespace();
+		let mut next = |what: &'static str| tokens.next()
+			.ok_or_else(|| ParseMotionError::new("", what));
+		let number = |token: &str| token.parse::<isize>()
+			.map_err(|_| ParseMotionError::new(token, "Invalid coordinate"));
+
+		let motion = match next("Missing motion")? {
+			"J" => Self::Jump(number(next("Missing x offset")?)?,
+				number(next("Missing y offset")?)?),
+			"T" => Self::Teleport(number(next("Missing x")?)?,
+				number(next("Missing y")?)?),
+			direction => {
+				let direction = direction.parse::<Move>()?;
+				let count = next("Missing count")?;
+				Self::Steps {
+					direction,
+					count: count.parse::<usize>()
+						.map_err(|_| ParseMotionError::new(count, "Invalid count"))?,
+				}
+			},
+		};
+		if let Some(token) = tokens.next() {
+			return Err(ParseMotionError::new(token, "Unexpected token"));
+		}
+		Ok(motion)
+	}
+}
+
+/// Parse the motions of the head, one per line
+///
+/// # Errors
+///
+/// Returns a `String` naming the line of the first motion that cannot be
+/// parsed.
+pub fn parse_motions(data: &str) -> Result<Vec<Motion>, String> {
+	data.lines()
+		.enumerate()
+		.filter(|(_, line)| !line.trim().is_empty())
+		.map(|(idx, line)| line.trim().parse::<Motion>()
+			.map_err(|e| format!("Line {}: {e}", idx + 1)))
+		.collect()
+}
+
+/// Where a knot goes when the knot before it moved to `head`
+const fn follow(head: (isize, isize), tail: (isize, isize)) -> (isize, isize) {
+	let (dx, dy) = (head.0 - tail.0, head.1 - tail.1);
+	if dx.abs() > 1 || dy.abs() > 1 {
+		// One step in each direction it lags behind in
+		(tail.0 + dx.signum(), tail.1 + dy.signum())
+	} else {
+		tail
+	}
+}
+
+/// A rope of knots, whose head is moved around, recording where every knot
+/// was after every step
+///
+/// Positions are `(x, y)` pairs, with `x` growing to the right and `y` growing
+/// upwards, every knot starting at `(0, 0)`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Rope {
 	knots: Vec<(isize, isize)>,
-	visited: HashSet<(isize, isize)>
+	// The positions of every knot after every step, starting with the start
+	trajectories: Vec<Vec<(isize, isize)>>,
+	motion_ends: Vec<usize>,
 }
 
-impl RopeModel {
-	fn new(knots: usize) -> Self {
-		//Self { head: (0,0), tail: (0,0), visited: HashSet::new() }
+impl Rope {
+	/// Build a rope with the given number of knots, the head included
+	///
+	/// A rope always has at least its head.
+	#[must_use]
+	pub fn new(knots: usize) -> Self {
+		let knots = knots.max(1);
 		Self {
-			knots: vec![(0,0); knots+1],
-			visited: HashSet::new()
+			knots: vec![(0, 0); knots],
+			trajectories: vec![vec![(0, 0)]; knots],
+			motion_ends: Vec::new(),
 		}
 	}
 
-	fn apply(&mut self, mv: Move) {
-		// Move the head
-		let head = self.knots.get_mut(0).expect("Broken!");
-		*head = match mv {
-			Move::Up	=> (head.0, head.1+1),
-			Move::Down	=> (head.0, head.1-1),
-			Move::Left	=> (head.0-1, head.1),
-			Move::Right	=> (head.0+1, head.1)
-		};
+	/// The current positions of the knots, head first
+	#[must_use]
+	pub fn knots(&self) -> &[(isize, isize)] {
+		&self.knots
+	}
 
-		// Fix tail
-		// if needed
-		//println!("H: ({}, {})", self.head.0, self.head.1);
-		//println!("D: {}", diff);
-		// Scan through all of the ropes
-		for idx in 0..self.knots.len()-1 {
-			let head = self.knots.get(idx).expect("Head");
-			let tail = self.knots.get(idx+1).expect("Tail");
-			if isize::abs(head.0 - tail.0) > 1
-				|| isize::abs(head.1 - tail.1) > 1 {
-				// Fix vector
-				let mut fix_vec = (head.0 - tail.0, head.1 - tail.1);
-				// Now, make it only one move
-				fix_vec = (fix_vec.0.signum(), fix_vec.1.signum());
-				// Apply
-				*self.knots.get_mut(idx+1).unwrap() = (tail.0 + fix_vec.0, tail.1 + fix_vec.1);
-			}
+	/// How many single steps the head took so far
+	#[must_use]
+	pub fn steps(&self) -> usize {
+		self.trajectories[0].len() - 1
+	}
+
+	/// The number of steps taken at the end of every motion applied so far
+	#[must_use]
+	pub fn motion_ends(&self) -> &[usize] {
+		&self.motion_ends
+	}
+
+	/// Move the head a single step, and let the other knots follow
+	pub fn step(&mut self, direction: Move) {
+		let (dx, dy) = direction.offset();
+		self.knots[0] = (self.knots[0].0 + dx, self.knots[0].1 + dy);
+		for idx in 1..self.knots.len() {
+			self.knots[idx] = follow(self.knots[idx - 1], self.knots[idx]);
+		}
+		for (trajectory, &knot) in self.trajectories.iter_mut().zip(&self.knots) {
+			trajectory.push(knot);
+		}
+	}
+
+	/// Move the whole rope by an offset, as a single step
+	pub fn shift(&mut self, (dx, dy): (isize, isize)) {
+		for (knot, trajectory) in self.knots.iter_mut().zip(&mut self.trajectories) {
+			*knot = (knot.0 + dx, knot.1 + dy);
+			trajectory.push(*knot);
+		}
+	}
+
+	/// Apply a whole motion
+	pub fn apply(&mut self, motion: &Motion) {
+		match *motion {
+			Motion::Steps { direction, count } => for _ in 0..count {
+				self.step(direction);
+			},
+			Motion::Jump(dx, dy) => self.shift((dx, dy)),
+			Motion::Teleport(x, y) =>
+				self.shift((x - self.knots[0].0, y - self.knots[0].1)),
 		}
+		self.motion_ends.push(self.steps());
+	}
+
+	/// Apply several motions in order
+	pub fn run(&mut self, motions: &[Motion]) {
+		for motion in motions {
+			self.apply(motion);
+		}
+	}
+
+	/// Every position of a knot, from the start and after every step
+	///
+	/// # Return value
+	///
+	/// Returns `None` if there is no such knot.
+	#[must_use]
+	pub fn trajectory(&self, knot: usize) -> Option<&[(isize, isize)]> {
+		self.trajectories.get(knot).map(Vec::as_slice)
+	}
+
+	/// The positions of all knots, head first, after the given number of
+	/// steps, zero being the start
+	///
+	/// # Return value
+	///
+	/// Returns `None` if fewer steps were taken.
+	#[must_use]
+	pub fn snapshot(&self, step: usize) -> Option<Vec<(isize, isize)>> {
+		(step <= self.steps())
+			.then(|| self.trajectories.iter().map(|t| t[step]).collect())
+	}
 
-		//println!("T: ({}, {})", self.tail.0, self.tail.1);
+	/// Every position a knot visited at least once
+	///
+	/// # Return value
+	///
+	/// Returns an empty set if there is no such knot.
+	#[must_use]
+	pub fn visited(&self, knot: usize) -> HashSet<(isize, isize)> {
+		self.trajectory(knot)
+			.map(|t| t.iter().copied().collect())
+			.unwrap_or_default()
+	}
 
-		// Record tail position
-		self.visited.insert(*self.knots.last().unwrap());
+	/// The smallest area holding the start and every position of every knot,
+	/// as ranges of `x` and `y`
+	#[must_use]
+	pub fn bounds(&self) -> (RangeInclusive<isize>, RangeInclusive<isize>) {
+		let (min_x, max_x, min_y, max_y) = self.trajectories.iter()
+			.flatten()
+			.fold((0, 0, 0, 0), |(a, b, c, d), &(x, y)|
+				(a.min(x), b.max(x), c.min(y), d.max(y)));
+		(min_x..=max_x, min_y..=max_y)
 	}
 
-	fn apply_all(&mut self, mv: Move, cnt: usize) {
-		// Apply n times
-		for _ in 0..cnt {
-			self.apply(mv);
+	/// The label of a knot : `H` for the head, `T` for the tail of a rope of
+	/// two knots, and the knot's number otherwise
+	fn label(&self, knot: usize) -> char {
+		match knot {
+			0 => 'H',
+			1 if self.knots.len() == 2 => 'T',
+			k => u32::try_from(k).ok()
+				.and_then(|k| char::from_digit(k, 10))
+				.unwrap_or('*'),
 		}
 	}
 
-	const fn tail_positions(&self) -> &HashSet<(isize, isize)> {
-		&self.visited
+	fn draw<F>(&self, bounds: Option<(RangeInclusive<isize>, RangeInclusive<isize>)>,
+			   tile: F) -> String
+		where F: Fn(isize, isize) -> char {
+		let (xs, ys) = bounds.unwrap_or_else(|| self.bounds());
+		// Rows are drawn from the top, where y is the largest
+		ys.rev()
+			.map(|y| xs.clone().map(|x| tile(x, y)).collect::<String>())
+			.collect::<Vec<String>>()
+			.join("\n")
+	}
+
+	/// Draw the knots after the given number of steps, like in the puzzle
+	///
+	/// When knots overlap, the one closest to the head is drawn. The start is
+	/// drawn as `s` when no knot is on it.
+	///
+	/// # Arguments
+	///
+	///  - `step` : the number of steps taken, zero being the start
+	///  - `bounds` : if set, the ranges of `x` and `y` drawn, otherwise the
+	///    [`Rope::bounds`]
+	///
+	/// # Return value
+	///
+	/// Returns `None` if fewer steps were taken.
+	#[must_use]
+	pub fn render(&self, step: usize,
+				  bounds: Option<(RangeInclusive<isize>, RangeInclusive<isize>)>)
+		-> Option<String> {
+		let knots = self.snapshot(step)?;
+		Some(self.draw(bounds, |x, y| {
+			knots.iter()
+				.position(|&k| k == (x, y))
+				.map_or_else(|| if (x, y) == (0, 0) { 's' } else { '.' },
+					|k| self.label(k))
+		}))
+	}
+
+	/// Draw the positions a knot visited as `#`, like in the puzzle, the start
+	/// being drawn as `s`
+	///
+	/// # Arguments
+	///
+	///  - `knot` : the knot, zero being the head
+	///  - `bounds` : if set, the ranges of `x` and `y` drawn, otherwise the
+	///    [`Rope::bounds`]
+	#[must_use]
+	pub fn render_visited(&self, knot: usize,
+						  bounds: Option<(RangeInclusive<isize>, RangeInclusive<isize>)>)
+		-> String {
+		let visited = self.visited(knot);
+		self.draw(bounds, |x, y| {
+			if (x, y) == (0, 0) {
+				's'
+			} else if visited.contains(&(x, y)) {
+				'#'
+			} else {
+				'.'
+			}
+		})
 	}
 }
 
@@ -147,18 +421,8 @@ impl RopeModel {
 ///
 /// If any conversion assumed to be valid with the input fails, panics.
 #[must_use]
-#[allow(clippy::missing_const_for_fn)]
 pub fn solve_part_two(data: &str) -> usize {
-	let mut model = RopeModel::new(9);
-	data.split('\n')
-		.map(|x| {
-			let mut divs = x.split(' ');
-			let mv = divs.next().unwrap().parse::<Move>().unwrap();
-			let count = divs.next().unwrap().parse::<usize>().unwrap();
-			(mv, count)
-		})
-		.for_each(|(mv, count)| model.apply_all(mv, count));
-	model.tail_positions().iter().count()
+	dense::visited_counts(&parse_motions(data).unwrap(), 10)[9]
 }
 
 // vim: set tw=80:
@@ -3,8 +3,7 @@
 
 extern crate common;
 use common::read_data;
-mod lib;
-use lib::{solve_part_one, solve_part_two};
+use day09::{solve_part_one, solve_part_two};
 
 #[doc(hidden)]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -23,4 +22,140 @@ mod test {
 	test!(day09_01_example1, 1, 13, "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2");
 	test!(day09_02_example1, 2, 1, "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2");
 	test!(day09_02_example2, 2, 36, "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20");
+
+	#[test]
+	fn day09_rope_render() {
+		use day09::{parse_motions, Rope};
+		let mut rope = Rope::new(2);
+		rope.run(&parse_motions("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2").unwrap());
+		assert_eq!(rope.steps(), 24);
+		assert_eq!(rope.motion_ends(), &[4, 8, 11, 12, 16, 17, 22, 24]);
+		let bounds = Some((0..=5, 0..=4));
+		assert_eq!(rope.render(0, bounds.clone()).unwrap(),
+			"......\n......\n......\n......\nH.....");
+		assert_eq!(rope.render(6, bounds.clone()).unwrap(),
+			"......\n......\n....H.\n....T.\ns.....");
+		assert_eq!(rope.render(24, bounds.clone()).unwrap(),
+			"......\n......\n.TH...\n......\ns.....");
+		assert_eq!(rope.render_visited(1, bounds),
+			"..##..\n...##.\n.####.\n....#.\ns###..");
+		assert!(rope.render(25, None).is_none());
+		assert_eq!(rope.trajectory(1).unwrap()[..4], [(0, 0), (0, 0), (1, 0), (2, 0)]);
+	}
+
+	#[test]
+	fn day09_rope_larger() {
+		use day09::{parse_motions, Rope};
+		let mut rope = Rope::new(10);
+		rope.run(&parse_motions("R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20").unwrap());
+		assert_eq!(rope.bounds(), (-11..=14, -5..=15));
+		assert_eq!(rope.render(rope.motion_ends()[1], Some((-11..=14, -5..=15))).unwrap(), "\
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+................H.........
+................1.........
+................2.........
+................3.........
+...............54.........
+..............6...........
+.............7............
+............8.............
+...........9..............
+..........................
+..........................
+..........................
+..........................
+..........................");
+		assert_eq!(rope.render_visited(9, None), "\
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+..........................
+#.........................
+#.............###.........
+#............#...#........
+.#..........#.....#.......
+..#..........#.....#......
+...#........#.......#.....
+....#......s.........#....
+.....#..............#.....
+......#............#......
+.......#..........#.......
+........#........#........
+.........########.........");
+	}
+
+	#[test]
+	fn day09_dense() {
+		use day09::{dense::visited_counts, parse_motions, Rope};
+		let motions = parse_motions("R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20").unwrap();
+		let mut rope = Rope::new(12);
+		rope.run(&motions);
+		assert_eq!(visited_counts(&motions, 12),
+			(0..12).map(|k| rope.visited(k).len()).collect::<Vec<usize>>());
+
+		// Knots past the first two hundred never move
+		let motions = parse_motions("R 200\nL 100\nU 3\nD 3").unwrap();
+		let counts = visited_counts(&motions, 2000);
+		let mut rope = Rope::new(210);
+		rope.run(&motions);
+		assert_eq!(counts[..210],
+			(0..210).map(|k| rope.visited(k).len()).collect::<Vec<usize>>());
+		assert_eq!(counts[200..], [1; 1800]);
+	}
+
+	#[test]
+	fn day09_dense_large() {
+		use day09::{dense::visited_counts, parse_motions};
+		// Long diagonals and corners only touch a thin part of their area
+		let motions = parse_motions("UR 200000").unwrap();
+		assert_eq!(visited_counts(&motions, 10),
+			(0..10).map(|k| 200_001 - k).collect::<Vec<usize>>());
+		let motions = parse_motions("R 1000000\nU 1000000").unwrap();
+		assert_eq!(visited_counts(&motions, 2), [2_000_001, 1_999_999]);
+
+		// A million motions climbing a staircase, where the followers cut the
+		// corners of the steps
+		let log = "R 2\nU 2\n".repeat(500_000);
+		let counts = visited_counts(&parse_motions(&log).unwrap(), 10);
+		assert_eq!(counts[0], 2_000_001);
+		assert_eq!(counts[1..], (1..10).map(|k| 1_000_002 - k).collect::<Vec<usize>>());
+	}
+
+	#[test]
+	fn day09_richer_motions() {
+		use day09::{dense::visited_counts, parse_motions, Motion, Move, Rope};
+		let motions = parse_motions("R 3\nUL 2\nJ 5 -1\nDR 3\nT -4 2\nL 2").unwrap();
+		assert_eq!(motions[1], Motion::Steps { direction: Move::UpLeft, count: 2 });
+		assert_eq!(motions[2], Motion::Jump(5, -1));
+		assert_eq!(motions[4], Motion::Teleport(-4, 2));
+
+		let mut rope = Rope::new(3);
+		rope.run(&motions);
+		assert_eq!(rope.snapshot(5).unwrap(), vec![(1, 2), (1, 1), (1, 0)]);
+		// The whole rope jumps, keeping its shape
+		assert_eq!(rope.snapshot(6).unwrap(), vec![(6, 1), (6, 0), (6, -1)]);
+		assert_eq!(rope.snapshot(9).unwrap(), vec![(9, -2), (8, -2), (7, -2)]);
+		assert_eq!(rope.snapshot(10).unwrap(), vec![(-4, 2), (-5, 2), (-6, 2)]);
+		assert_eq!(rope.knots(), &[(-6, 2), (-5, 2), (-6, 2)]);
+		assert_eq!(visited_counts(&motions, 3),
+			(0..3).map(|k| rope.visited(k).len()).collect::<Vec<usize>>());
+
+		let err = "X 3".parse::<Motion>().unwrap_err();
+		assert_eq!(err.token(), "X");
+		assert_eq!(err.to_string(), "Unknown direction \"X\"");
+		assert_eq!("J 1 y".parse::<Motion>().unwrap_err().token(), "y");
+		assert_eq!(parse_motions("R 1\nU -1").unwrap_err(),
+			"Line 2: Invalid count \"-1\"");
+	}
 }
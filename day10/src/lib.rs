@@ -13,7 +13,9 @@
 #![deny(missing_docs)]
 #![deny(rustdoc::missing_crate_level_docs)]
 
-use std::collections::HashMap;
+use std::{fmt, str::FromStr};
+
+pub mod ocr;
 
 /// Solve Advent of Code day 10 part one
 ///
@@ -30,77 +32,398 @@ use std::collections::HashMap;
 ///
 /// If any conversion assumed to be valid with the input fails, panics.
 #[must_use]
-#[allow(clippy::missing_const_for_fn)]
 pub fn solve_part_one(data: &str) -> usize {
-	let mut cpu = Device::new(40, 6, false);
-
-	data.split('\n')
-		.for_each(|s| {
-			let mut splits = s.split(' ');
-			let op = splits.next().unwrap();
-			if op == "noop" {
-				cpu.pass_cycle();
-			} else {
-				let count = splits.next().unwrap().parse::<isize>().unwrap();
-				cpu.addx(count);
-			}
-		});
+	let program = parse_program(data).unwrap();
+	let mut sampler = SignalSampler::default();
+	Cpu::new().run(&program, &mut [&mut sampler]);
+	sampler.sum().try_into().unwrap()
+}
 
-	cpu.spit()
+/// A decoded instruction of the device's CPU
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Instruction {
+	/// Do nothing for a cycle
+	Noop,
+	/// Add a value to the `X` register, over two cycles
+	Addx(isize),
 }
 
-struct Device {
-	every: isize,
-	howmany: isize,
-	cyclecount: isize,
-	value: isize,
-	screen: HashMap<(isize, isize), bool>,
-	record: bool,
-	strengths: Vec<isize>,
-	start: isize
+impl Instruction {
+	/// How many cycles the instruction takes to complete
+	#[must_use]
+	pub const fn cycles(&self) -> usize {
+		match self {
+			Self::Noop => 1,
+			Self::Addx(_) => 2,
+		}
+	}
+
+	/// Apply the effect of the instruction to the registers, once all of its
+	/// cycles are over
+	pub const fn apply(&self, registers: &mut Registers) {
+		match self {
+			Self::Noop => {},
+			Self::Addx(v) => registers.x += *v,
+		}
+	}
 }
 
-impl Device {
-	fn new(every: isize, howmany: isize, record: bool) -> Self {
-		Self {
-			start: 20, every, howmany, cyclecount: 0, value: 1,
-			strengths: Vec::new(), record, screen: HashMap::new()
+impl FromStr for Instruction {
+	type Err = String;
+	fn from_str(st: &str) -> Result<Self, Self::Err> {
+		let mut tokens = st.split_whitespace();
+		let instruction = match tokens.next() {
+			Some("noop") => Self::Noop,
+			Some("addx") => {
+				let value = tokens.next()
+					.ok_or("Missing operand for \"addx\"")?;
+				Self::Addx(value.parse::<isize>()
+					.map_err(|e| format!("Invalid operand \"{value}\": {e}"))?)
+			},
+			Some(op) => return Err(format!("Unknown instruction \"{op}\"")),
+			None => return Err(String::from("Empty instruction")),
+		};
+		if let Some(token) = tokens.next() {
+			return Err(format!("Unexpected \"{token}\" after the instruction"));
 		}
+		Ok(instruction)
 	}
+}
 
-	fn update_screen(&mut self) {
-		// Count the pixel on screen (maybe)
-		let pixelbeam = self.cyclecount % 40;
-		for dx in [-1, 0, 1] {
-			if self.value + dx == pixelbeam {
-				self.screen.insert((self.cyclecount / 40, self.value + dx), true);
+impl fmt::Display for Instruction {
+	fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Noop => write!(fmt, "noop"),
+			Self::Addx(v) => write!(fmt, "addx {v}"),
+		}
+	}
+}
+
+/// Decode a program, one instruction per line
+///
+/// # Errors
+///
+/// Returns a `String` naming the line of the first instruction that cannot be
+/// decoded.
+pub fn parse_program(data: &str) -> Result<Vec<Instruction>, String> {
+	data.lines()
+		.enumerate()
+		.filter(|(_, line)| !line.trim().is_empty())
+		.map(|(idx, line)| line.parse::<Instruction>()
+			.map_err(|e| format!("Line {}: {e}", idx + 1)))
+		.collect()
+}
+
+/// The registers of the CPU
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Registers {
+	/// The `X` register, which starts at 1
+	pub x: isize,
+}
+
+impl Default for Registers {
+	fn default() -> Self {
+		Self { x: 1 }
+	}
+}
+
+/// Something that watches the CPU, cycle after cycle
+///
+/// Closures taking the cycle and the registers are observers too.
+pub trait Observer {
+	/// Called during every cycle, starting from cycle 1, with the registers
+	/// as they are during that cycle
+	fn cycle(&mut self, cycle: usize, registers: &Registers);
+}
+
+impl<F: FnMut(usize, &Registers)> Observer for F {
+	fn cycle(&mut self, cycle: usize, registers: &Registers) {
+		self(cycle, registers);
+	}
+}
+
+/// The CPU of the device, running instructions and keeping time
+///
+/// Instructions only describe how long they take and what they do once they
+/// are over, while the CPU calls every [`Observer`] during every cycle.
+#[derive(Debug, Clone, Default)]
+pub struct Cpu {
+	registers: Registers,
+	cycle: usize,
+}
+
+impl Cpu {
+	/// Build a new CPU, with `X` at 1, before the first cycle
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// The current state of the registers
+	#[must_use]
+	pub const fn registers(&self) -> &Registers {
+		&self.registers
+	}
+
+	/// How many cycles were completed so far
+	#[must_use]
+	pub const fn cycle(&self) -> usize {
+		self.cycle
+	}
+
+	/// Run one instruction, calling the observers during each of its cycles
+	pub fn execute(&mut self, instruction: &Instruction,
+				   observers: &mut [&mut dyn Observer]) {
+		for _ in 0..instruction.cycles() {
+			self.cycle += 1;
+			for observer in observers.iter_mut() {
+				observer.cycle(self.cycle, &self.registers);
 			}
 		}
+		instruction.apply(&mut self.registers);
+	}
+
+	/// Run a whole program, calling the observers during every cycle
+	pub fn run(&mut self, program: &[Instruction],
+			   observers: &mut [&mut dyn Observer]) {
+		for instruction in program {
+			self.execute(instruction, observers);
+		}
+	}
+}
+
+/// An [`Observer`] sampling the signal strength, the product of the cycle
+/// number and `X`, at regular cycles
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SignalSampler {
+	start: usize,
+	every: usize,
+	count: Option<usize>,
+	samples: Vec<isize>,
+}
+
+impl Default for SignalSampler {
+	/// The puzzle's sampler, during the 20th cycle and every 40 cycles after
+	/// that, six times in all
+	fn default() -> Self {
+		Self::new(20, 40, Some(6))
+	}
+}
+
+impl SignalSampler {
+	/// Build a new sampler
+	///
+	/// # Arguments
+	///
+	///  - `start` : the first cycle sampled
+	///  - `every` : how many cycles are between two samples, which must not be
+	///    zero
+	///  - `count` : if set, how many samples are taken at most
+	#[must_use]
+	pub const fn new(start: usize, every: usize, count: Option<usize>) -> Self {
+		Self { start, every, count, samples: Vec::new() }
+	}
+
+	/// The signal strengths sampled so far
+	#[must_use]
+	pub fn samples(&self) -> &[isize] {
+		&self.samples
+	}
+
+	/// The sum of the signal strengths sampled so far
+	#[must_use]
+	pub fn sum(&self) -> isize {
+		self.samples.iter().sum()
+	}
+}
+
+impl Observer for SignalSampler {
+	fn cycle(&mut self, cycle: usize, registers: &Registers) {
+		if cycle < self.start || !(cycle - self.start).is_multiple_of(self.every)
+			|| self.count.is_some_and(|c| self.samples.len() >= c) {
+			return;
+		}
+		// Cycle counts stay far below isize::MAX
+		self.samples.push(registers.x * cycle.cast_signed());
+	}
+}
+
+/// An [`Observer`] drawing on the CRT screen
+///
+/// During every cycle, the beam draws one pixel, left to right and top to
+/// bottom, wrapping around to the top after the last pixel. The pixel is lit
+/// when the sprite, centered on `X`, covers the column of the beam.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Crt {
+	width: usize,
+	height: usize,
+	sprite: usize,
+	pixels: Vec<bool>,
+	frames: Option<Vec<Frame>>,
+}
 
+impl Default for Crt {
+	/// The puzzle's screen, 40 pixels wide and 6 pixels high, with a sprite
+	/// three pixels wide
+	fn default() -> Self {
+		Self {
+			width: 40,
+			height: 6,
+			sprite: 3,
+			pixels: vec![false; 240],
+			frames: None,
+		}
 	}
+}
 
-	fn pass_cycle(&mut self) {
-		self.cyclecount += 1;
-		if self.record || self.cyclecount < self.start { return; }
-		if (self.cyclecount - self.start) % self.every == 0 && self.strengths.len() <= self.howmany.try_into().unwrap() {
-			self.strengths.push(self.value * self.cyclecount);
+impl Crt {
+	/// Build a new, dark, screen
+	///
+	/// # Arguments
+	///
+	///  - `width` : how many pixels are in a row
+	///  - `height` : how many rows are on the screen
+	///  - `sprite` : how many pixels wide the sprite is, its center being the
+	///    middle pixel, or the left one of the two middle pixels
+	///
+	/// # Errors
+	///
+	/// Returns a `String` if a dimension is zero.
+	pub fn new(width: usize, height: usize, sprite: usize) -> Result<Self, String> {
+		if width == 0 || height == 0 || sprite == 0 {
+			return Err(format!("Invalid CRT of {width}x{height} with a sprite of {sprite}"));
 		}
+		Ok(Self {
+			width,
+			height,
+			sprite,
+			pixels: vec![false; width * height],
+			frames: None,
+		})
+	}
+
+	/// Record a [`Frame`] during every cycle
+	#[must_use]
+	pub fn recording(mut self) -> Self {
+		self.frames = Some(Vec::new());
+		self
+	}
+
+	/// How many pixels are in a row
+	#[must_use]
+	pub const fn width(&self) -> usize {
+		self.width
+	}
+
+	/// How many rows are on the screen
+	#[must_use]
+	pub const fn height(&self) -> usize {
+		self.height
 	}
 
-	fn addx(&mut self, howmuch: isize) {
-		self.pass_cycle();
-		if self.record { self.update_screen(); }
-		self.pass_cycle();
-		self.value += howmuch;
-		if self.record { self.update_screen(); }
+	/// How many pixels wide the sprite is
+	#[must_use]
+	pub const fn sprite(&self) -> usize {
+		self.sprite
 	}
 
-	fn spit(&self) -> usize {
-		self.strengths.iter().sum::<isize>().try_into().unwrap()
+	/// Whether the pixel at column `x` and row `y` is lit
+	#[must_use]
+	pub fn lit(&self, x: usize, y: usize) -> bool {
+		x < self.width && self.pixels.get(y * self.width + x).copied().unwrap_or(false)
 	}
 
-	const fn get_screen(&self) -> &HashMap<(isize, isize), bool> {
-		&self.screen
+	/// The frames recorded so far, if recording
+	#[must_use]
+	pub fn frames(&self) -> &[Frame] {
+		self.frames.as_deref().unwrap_or_default()
+	}
+
+	/// Whether the sprite centered on `x` covers a column
+	const fn covers(&self, x: isize, column: usize) -> bool {
+		// Screens are far narrower than isize::MAX
+		let left = x - (self.sprite.cast_signed() - 1) / 2;
+		let column = column.cast_signed();
+		left <= column && column < left + self.sprite.cast_signed()
+	}
+}
+
+impl Observer for Crt {
+	fn cycle(&mut self, cycle: usize, registers: &Registers) {
+		let position = (cycle - 1) % self.pixels.len();
+		let (column, row) = (position % self.width, position / self.width);
+		let lit = self.covers(registers.x, column);
+		// A new pass of the beam starts on a dark screen
+		if position == 0 {
+			self.pixels.fill(false);
+		}
+		self.pixels[position] = lit;
+
+		if self.frames.is_none() {
+			return;
+		}
+		let sprite = (0..self.width)
+			.map(|c| if self.covers(registers.x, c) { '#' } else { '.' })
+			.collect::<String>();
+		let current_row = self.pixels[row * self.width..=position].iter()
+			.map(|&p| if p { '#' } else { '.' })
+			.collect::<String>();
+		if let Some(frames) = self.frames.as_mut() {
+			frames.push(Frame {
+				cycle,
+				x: registers.x,
+				column,
+				row,
+				lit,
+				sprite,
+				current_row,
+			});
+		}
+	}
+}
+
+impl fmt::Display for Crt {
+	fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (y, row) in self.pixels.chunks(self.width).enumerate() {
+			if y > 0 {
+				writeln!(fmt)?;
+			}
+			for &pixel in row {
+				write!(fmt, "{}", if pixel { '#' } else { '.' })?;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// What the [`Crt`] does during one cycle
+///
+/// It is written like the puzzle's walkthrough, showing the sprite over the
+/// whole row and the current row up to the beam.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Frame {
+	/// The cycle, starting from 1
+	pub cycle: usize,
+	/// The value of `X` during the cycle
+	pub x: isize,
+	/// The column of the beam
+	pub column: usize,
+	/// The row of the beam
+	pub row: usize,
+	/// Whether the pixel under the beam is lit
+	pub lit: bool,
+	/// The row of the sprite, `#` where it is
+	pub sprite: String,
+	/// The row of the beam, up to and including the beam
+	pub current_row: String,
+}
+
+impl fmt::Display for Frame {
+	fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(fmt, "Sprite position: {}", self.sprite)?;
+		writeln!(fmt, "During cycle {:>2}: CRT draws pixel in position {}",
+			self.cycle, self.column)?;
+		write!(fmt, "Current CRT row: {}", self.current_row)
 	}
 }
 
@@ -112,7 +435,7 @@ impl Device {
 ///
 /// # Return value
 ///
-/// This function returns a `usize`, the result for part
+/// This function returns a `String`, the picture drawn on the CRT for part
 /// two of advent of code day 10.
 ///
 /// # Panics
@@ -120,31 +443,8 @@ impl Device {
 /// If any conversion assumed to be valid with the input fails, panics.
 #[must_use]
 pub fn solve_part_two(data: &str) -> String {
-	let mut cpu = Device::new(20, 20, true);
-	cpu.update_screen();
-
-	data.split('\n')
-		.for_each(|s| {
-			let mut splits = s.split(' ');
-			let op = splits.next().unwrap();
-			if op == "noop" {
-				cpu.pass_cycle();
-				cpu.update_screen();
-			} else {
-				let count = splits.next().unwrap().parse::<isize>().unwrap();
-				cpu.addx(count);
-			}
-
-		});
-
-	print_map(cpu.get_screen())
+	let program = parse_program(data).unwrap();
+	let mut crt = Crt::default();
+	Cpu::new().run(&program, &mut [&mut crt]);
+	crt.to_string()
 }
-
-fn print_map(sc: &HashMap<(isize, isize), bool>) -> String {
-	(0..6_isize).map(|y: isize| {
-		(0..40_isize).map(|x| {
-			if *sc.get(&(y,x)).unwrap_or(&false) { "#" } else { "." }
-		}).collect::<Vec<&str>>().as_slice().join("")
-	}).collect::<Vec<String>>().as_slice().join("\n")
-}
-
@@ -3,8 +3,7 @@
 
 extern crate common;
 use common::read_data;
-mod lib;
-use lib::{solve_part_one, solve_part_two};
+use day10::{solve_part_one, solve_part_two};
 
 #[doc(hidden)]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -23,4 +22,72 @@ mod test {
 	test!(day10_01_example1, 1, 13140, "addx 15\naddx -11\naddx 6\naddx -3\naddx 5\naddx -1\naddx -8\naddx 13\naddx 4\nnoop\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx -35\naddx 1\naddx 24\naddx -19\naddx 1\naddx 16\naddx -11\nnoop\nnoop\naddx 21\naddx -15\nnoop\nnoop\naddx -3\naddx 9\naddx 1\naddx -3\naddx 8\naddx 1\naddx 5\nnoop\nnoop\nnoop\nnoop\nnoop\naddx -36\nnoop\naddx 1\naddx 7\nnoop\nnoop\nnoop\naddx 2\naddx 6\nnoop\nnoop\nnoop\nnoop\nnoop\naddx 1\nnoop\nnoop\naddx 7\naddx 1\nnoop\naddx -13\naddx 13\naddx 7\nnoop\naddx 1\naddx -33\nnoop\nnoop\nnoop\naddx 2\nnoop\nnoop\nnoop\naddx 8\nnoop\naddx -1\naddx 2\naddx 1\nnoop\naddx 17\naddx -9\naddx 1\naddx 1\naddx -3\naddx 11\nnoop\nnoop\naddx 1\nnoop\naddx 1\nnoop\nnoop\naddx -13\naddx -19\naddx 1\naddx 3\naddx 26\naddx -30\naddx 12\naddx -1\naddx 3\naddx 1\nnoop\nnoop\nnoop\naddx -9\naddx 18\naddx 1\naddx 2\nnoop\nnoop\naddx 9\nnoop\nnoop\nnoop\naddx -1\naddx 2\naddx -37\naddx 1\naddx 3\nnoop\naddx 15\naddx -21\naddx 22\naddx -6\naddx 1\nnoop\naddx 2\naddx 1\nnoop\naddx -10\nnoop\nnoop\naddx 20\naddx 1\naddx 2\naddx 2\naddx -6\naddx -11\nnoop\nnoop\nnoop");
 	test!(day10_02_example1, 2, "##..##..##..##..##..##..##..##..##..##..\n###...###...###...###...###...###...###.\n####....####....####....####....####....\n#####.....#####.....#####.....#####.....\n######......######......######......####\n#######.......#######.......#######.....", "addx 15\naddx -11\naddx 6\naddx -3\naddx 5\naddx -1\naddx -8\naddx 13\naddx 4\nnoop\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx -35\naddx 1\naddx 24\naddx -19\naddx 1\naddx 16\naddx -11\nnoop\nnoop\naddx 21\naddx -15\nnoop\nnoop\naddx -3\naddx 9\naddx 1\naddx -3\naddx 8\naddx 1\naddx 5\nnoop\nnoop\nnoop\nnoop\nnoop\naddx -36\nnoop\naddx 1\naddx 7\nnoop\nnoop\nnoop\naddx 2\naddx 6\nnoop\nnoop\nnoop\nnoop\nnoop\naddx 1\nnoop\nnoop\naddx 7\naddx 1\nnoop\naddx -13\naddx 13\naddx 7\nnoop\naddx 1\naddx -33\nnoop\nnoop\nnoop\naddx 2\nnoop\nnoop\nnoop\naddx 8\nnoop\naddx -1\naddx 2\naddx 1\nnoop\naddx 17\naddx -9\naddx 1\naddx 1\naddx -3\naddx 11\nnoop\nnoop\naddx 1\nnoop\naddx 1\nnoop\nnoop\naddx -13\naddx -19\naddx 1\naddx 3\naddx 26\naddx -30\naddx 12\naddx -1\naddx 3\naddx 1\nnoop\nnoop\nnoop\naddx -9\naddx 18\naddx 1\naddx 2\nnoop\nnoop\naddx 9\nnoop\nnoop\nnoop\naddx -1\naddx 2\naddx -37\naddx 1\naddx 3\nnoop\naddx 15\naddx -21\naddx 22\naddx -6\naddx 1\nnoop\naddx 2\naddx 1\nnoop\naddx -10\nnoop\nnoop\naddx 20\naddx 1\naddx 2\naddx 2\naddx -6\naddx -11\nnoop\nnoop\nnoop");
 
+	#[test]
+	fn day10_cpu() {
+		use day10::{parse_program, Cpu, Instruction, Registers, SignalSampler};
+		let program = parse_program("noop\naddx 3\naddx -5").unwrap();
+		assert_eq!(program, vec![Instruction::Noop, Instruction::Addx(3),
+			Instruction::Addx(-5)]);
+
+		let mut xs = Vec::new();
+		let mut sampler = SignalSampler::new(1, 2, None);
+		let mut cpu = Cpu::new();
+		cpu.run(&program, &mut [
+			&mut |_, r: &Registers| xs.push(r.x),
+			&mut sampler]);
+		assert_eq!(xs, vec![1, 1, 1, 4, 4]);
+		assert_eq!(sampler.samples(), &[1, 3, 20]);
+		assert_eq!(cpu.cycle(), 5);
+		assert_eq!(cpu.registers().x, -1);
+
+		assert_eq!(parse_program("noop\nmulx 2").unwrap_err(),
+			"Line 2: Unknown instruction \"mulx\"");
+		assert!(parse_program("addx").is_err());
+	}
+
+	#[test]
+	fn day10_ocr() {
+		use day10::ocr::{recognise, OcrError};
+		let picture = "###..#..#..##..####..##....##.###..###..\n#..#.#.#..#..#....#.#..#....#.#..#.#..#.\n#..#.##...#..#...#..#..#....#.###..#..#.\n###..#.#..####..#...####....#.#..#.###..\n#.#..#.#..#..#.#....#..#.#..#.#..#.#.#..\n#..#.#..#.#..#.####.#..#..##..###..#..#.";
+		assert_eq!(recognise(picture).unwrap(), "RKAZAJBR");
+
+		let picture = picture.replacen("###..", "####.", 1);
+		let Err(OcrError::UnknownGlyphs { partial, glyphs }) = recognise(&picture) else {
+			panic!("The first glyph should be unknown");
+		};
+		assert_eq!(partial, "?KAZAJBR");
+		assert_eq!(glyphs, vec![(0, String::from("####\n#..#\n#..#\n###.\n#.#.\n#..#"))]);
+		assert!(matches!(recognise("#..#\n#..#"), Err(OcrError::Shape(_))));
+		// Too narrow for a glyph, or with columns left over
+		assert!(matches!(recognise(&["##"; 6].join("\n")), Err(OcrError::Shape(_))));
+		assert!(matches!(recognise(&"\n".repeat(6)), Err(OcrError::Shape(_))));
+		let wider = picture.lines().map(|l| format!("{l}##")).collect::<Vec<String>>();
+		assert!(matches!(recognise(&wider.join("\n")), Err(OcrError::Shape(_))));
+		// The spacing column after the last glyph is optional
+		let trimmed = picture.lines().map(|l| &l[..39]).collect::<Vec<&str>>();
+		assert_eq!(recognise(&trimmed.join("\n")).unwrap_err(),
+			OcrError::UnknownGlyphs { partial, glyphs });
+	}
+
+	#[test]
+	fn day10_crt() {
+		use day10::{parse_program, Cpu, Crt};
+		let program = parse_program("addx 15\naddx -11\naddx 6\naddx -3\naddx 5").unwrap();
+		let mut crt = Crt::default().recording();
+		Cpu::new().run(&program, &mut [&mut crt]);
+		assert_eq!(crt.frames().len(), 10);
+		assert_eq!(crt.frames()[2].to_string(), "\
+Sprite position: ...............###......................
+During cycle  3: CRT draws pixel in position 2
+Current CRT row: ##.");
+		assert!(!crt.frames()[2].lit);
+		assert_eq!(crt.frames()[9].current_row, "##..##..##");
+
+		// A wider sprite on a small screen
+		let mut crt = Crt::new(4, 2, 4).unwrap();
+		Cpu::new().run(&parse_program("noop\naddx 3\nnoop\nnoop\nnoop").unwrap(),
+			&mut [&mut crt]);
+		assert_eq!(crt.to_string(), "####\n....");
+		assert!(Crt::new(0, 6, 3).is_err());
+	}
 }
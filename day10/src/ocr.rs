@@ -0,0 +1,119 @@
+//! Reading the letters drawn on the CRT
+//!
+//! Letters are drawn in the usual Advent of Code font, where every glyph is
+//! four pixels wide and six pixels high, with a column of dark pixels between
+//! two glyphs. Only the capital letters of that font that are known to appear
+//! in puzzle answers are recognised.
+
+use std::fmt;
+
+/// The width of a glyph, in pixels
+pub const GLYPH_WIDTH: usize = 4;
+/// The height of a glyph, in pixels
+pub const GLYPH_HEIGHT: usize = 6;
+
+/// The known glyphs, as their six rows read top to bottom
+const GLYPHS: [(char, [&str; GLYPH_HEIGHT]); 17] = [
+	('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+	('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+	('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+	('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+	('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+	('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+	('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+	('I', ["###.", ".#..", ".#..", ".#..", ".#..", "###."]),
+	('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+	('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+	('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+	('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+	('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+	('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+	('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+	('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+	('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The error returned when a picture cannot be read
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum OcrError {
+	/// The picture is not six rows of the same width
+	Shape(String),
+	/// Some glyphs are not known letters
+	UnknownGlyphs {
+		/// The text read, with a `?` in place of every unknown glyph
+		partial: String,
+		/// The index and the picture, rows separated by newlines, of every
+		/// unknown glyph
+		glyphs: Vec<(usize, String)>,
+	},
+}
+
+impl fmt::Display for OcrError {
+	fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Shape(message) => write!(fmt, "{message}"),
+			Self::UnknownGlyphs { partial, glyphs } => {
+				let indices = glyphs.iter()
+					.map(|(idx, _)| idx.to_string())
+					.collect::<Vec<String>>()
+					.join(", ");
+				write!(fmt, "Unknown glyphs at positions {indices} in \"{partial}\"")
+			},
+		}
+	}
+}
+
+impl std::error::Error for OcrError {}
+
+/// Read the letters of a picture drawn with `#` for lit pixels and `.` for
+/// dark ones, like the one drawn by the [`Crt`](super::Crt)
+///
+/// # Errors
+///
+/// Returns an [`OcrError`] if the picture does not have the shape of a line of
+/// glyphs, that is six rows holding glyphs of four columns separated by one
+/// column, or if some glyphs are not known letters.
+pub fn recognise(picture: &str) -> Result<String, OcrError> {
+	let rows = picture.lines()
+		.map(|l| l.chars().map(|c| c == '#').collect::<Vec<bool>>())
+		.collect::<Vec<Vec<bool>>>();
+	if rows.len() != GLYPH_HEIGHT {
+		return Err(OcrError::Shape(format!(
+			"Expected {GLYPH_HEIGHT} rows, found {}", rows.len())));
+	}
+	let width = rows[0].len();
+	if rows.iter().any(|r| r.len() != width) {
+		return Err(OcrError::Shape(String::from("Rows have different widths")));
+	}
+	// Every glyph is followed by a spacing column, except maybe the last one
+	if width < GLYPH_WIDTH || !matches!(width % (GLYPH_WIDTH + 1), 0 | GLYPH_WIDTH) {
+		return Err(OcrError::Shape(format!(
+			"Width {width} does not fit whole glyphs of {GLYPH_WIDTH} columns")));
+	}
+
+	let mut partial = String::new();
+	let mut glyphs = Vec::new();
+	// The last glyph does not need a column of spacing after it
+	for (idx, left) in (0..=width.saturating_sub(GLYPH_WIDTH))
+		.step_by(GLYPH_WIDTH + 1)
+		.enumerate() {
+		let glyph = rows.iter()
+			.map(|r| r[left..left + GLYPH_WIDTH].iter()
+				.map(|&lit| if lit { '#' } else { '.' })
+				.collect::<String>())
+			.collect::<Vec<String>>();
+		if let Some((letter, _)) = GLYPHS.iter()
+			.find(|(_, known)| known.iter().eq(glyph.iter())) {
+			partial.push(*letter);
+		} else {
+			partial.push('?');
+			glyphs.push((idx, glyph.join("\n")));
+		}
+	}
+
+	if glyphs.is_empty() {
+		Ok(partial)
+	} else {
+		Err(OcrError::UnknownGlyphs { partial, glyphs })
+	}
+}
@@ -0,0 +1,173 @@
+//! Seeded generation of random troops
+//!
+//! Generated troops look like puzzle inputs : every monkey tests divisibility
+//! by a different prime, so that divisors are pairwise co-prime, throws to two
+//! other monkeys, and either adds to, multiplies or squares worry levels.
+//! Subtractions are never generated, so that generated troops can be played
+//! with any relief.
+//!
+//! Generation only depends on the seed and the settings, so the same
+//! [`Generator`] always builds the same troop, on every platform.
+
+use std::ops::RangeInclusive;
+
+use super::{Monkey, Operand, Operation, Operator, Troop};
+
+/// A small `SplitMix64` generator, whose output never changes from one build to
+/// the next
+#[derive(Debug, Clone)]
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+	const fn next(&mut self) -> u64 {
+		self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+		let mut z = self.0;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+		z ^ (z >> 31)
+	}
+
+	/// A value in the given range, assumed not to be empty
+	fn range(&mut self, range: &RangeInclusive<u64>) -> u64 {
+		let span = range.end() - range.start();
+		if span == u64::MAX {
+			return self.next();
+		}
+		range.start() + self.next() % (span + 1)
+	}
+
+	/// An index in `0..len`, assumed not to be empty
+	fn index(&mut self, len: usize) -> usize {
+		// The remainder is below len, so it fits in a usize
+		usize::try_from(self.next() % len as u64).unwrap_or_default()
+	}
+}
+
+/// The first `count` prime numbers
+fn primes(count: usize) -> Vec<u64> {
+	let mut primes: Vec<u64> = Vec::with_capacity(count);
+	let mut candidate = 2;
+	while primes.len() < count {
+		if primes.iter()
+			.take_while(|&&p| p * p <= candidate)
+			.all(|&p| !candidate.is_multiple_of(p)) {
+			primes.push(candidate);
+		}
+		candidate += 1;
+	}
+	primes
+}
+
+/// Settings for the generation of random troops
+///
+/// Past fifteen monkeys, the lcm of the divisors no longer fits in a `u64`,
+/// so troops without relief eventually overflow.
+#[derive(Debug, Clone)]
+pub struct Generator {
+	seed: u64,
+	monkeys: usize,
+	items: RangeInclusive<usize>,
+	worry: RangeInclusive<u64>,
+}
+
+impl Default for Generator {
+	fn default() -> Self {
+		Self {
+			seed: 0,
+			monkeys: 8,
+			items: 1..=8,
+			worry: 50..=99,
+		}
+	}
+}
+
+impl Generator {
+	/// Build a generator with the given seed, making troops of eight monkeys
+	/// that hold between one and eight items, of worry levels between 50 and
+	/// 99
+	#[must_use]
+	pub fn new(seed: u64) -> Self {
+		Self { seed, ..Self::default() }
+	}
+
+	/// Set how many monkeys are in the troop
+	#[must_use]
+	pub const fn monkeys(mut self, monkeys: usize) -> Self {
+		self.monkeys = monkeys;
+		self
+	}
+
+	/// Set how many items every monkey starts with
+	#[must_use]
+	pub const fn items(mut self, items: RangeInclusive<usize>) -> Self {
+		self.items = items;
+		self
+	}
+
+	/// Set the range of the starting worry levels
+	#[must_use]
+	pub const fn worry(mut self, worry: RangeInclusive<u64>) -> Self {
+		self.worry = worry;
+		self
+	}
+
+	/// Generate a troop
+	///
+	/// # Errors
+	///
+	/// Returns a `String` if there are fewer than two monkeys, since monkeys
+	/// cannot throw to themselves, or if a range is empty.
+	pub fn generate(&self) -> Result<Troop, String> {
+		if self.monkeys < 2 {
+			return Err(format!("Cannot build a troop of {} monkeys", self.monkeys));
+		} else if self.items.is_empty() || self.worry.is_empty() {
+			return Err(String::from("Empty range of items or worry levels"));
+		}
+		let mut rng = SplitMix64(self.seed);
+		let mut divisors = primes(self.monkeys);
+		// Shuffle the divisors so that small ones are not always first
+		for idx in (1..divisors.len()).rev() {
+			divisors.swap(idx, rng.index(idx + 1));
+		}
+		// One monkey squares worry levels, like in the puzzle
+		let square = rng.index(self.monkeys);
+
+		let monkeys = divisors.into_iter()
+			.enumerate()
+			.map(|(idx, divisor)| {
+				let count = self.items.start()
+					+ rng.index(self.items.end() - self.items.start() + 1);
+				let items = (0..count).map(|_| rng.range(&self.worry)).collect();
+				let (operator, operand) = if idx == square {
+					(Operator::Mul, Operand::Old)
+				} else if rng.next().is_multiple_of(2) {
+					(Operator::Add, Operand::Val(rng.range(&(1..=9))))
+				} else {
+					(Operator::Mul, Operand::Val(rng.range(&(2..=19))))
+				};
+				let operation = Operation { operator, operand };
+				// Pick two other monkeys, skipping over this one
+				let other = |rng: &mut SplitMix64, skip: &[usize]| {
+					let mut target = rng.index(self.monkeys - skip.len());
+					let mut skip = skip.to_vec();
+					skip.sort_unstable();
+					for s in skip {
+						if target >= s {
+							target += 1;
+						}
+					}
+					target
+				};
+				let if_true = other(&mut rng, &[idx]);
+				// With two monkeys, both throws go to the other one
+				let if_false = if self.monkeys > 2 {
+					other(&mut rng, &[idx, if_true])
+				} else {
+					if_true
+				};
+				Monkey::new(items, operation, divisor, (if_true, if_false))
+			})
+			.collect::<Vec<Monkey>>();
+		Troop::new(monkeys)
+	}
+}
@@ -13,7 +13,10 @@
 #![deny(missing_docs)]
 #![deny(rustdoc::missing_crate_level_docs)]
 
-use std::collections::{HashSet, VecDeque};
+use std::collections::VecDeque;
+
+pub mod generate;
+pub mod report;
 
 /// Solve Advent of Code day 11 part one
 ///
@@ -31,170 +34,577 @@ use std::collections::{HashSet, VecDeque};
 /// If any conversion assumed to be valid with the input fails, panics.
 #[must_use]
 pub fn solve_part_one(data: &str) -> usize {
-	let mut monkeys = data.split("\n\n")
-		.map(|st| st.parse::<Monkey>().unwrap())
-		.collect::<Vec<Monkey>>();
-	// We run 20 cycles
-	for _ in 0..20 {
-		for idx in 0..monkeys.len() {
-			let base_monkey = monkeys.get_mut(idx).unwrap();
-			let div = base_monkey.get_div();
-			let out_monkeys = base_monkey.get_out_monkeys();
-			let op = base_monkey.get_op();
-			base_monkey.inspect_count_up();
-			let items = base_monkey.get_values_mut();
-			// Index of where stuff ends up
-			let mut out_list: Vec<(usize, usize)> = Vec::new();
-			for _ in 0..items.len() {
-				let v = op.apply(items.pop_front().unwrap()) / 3;
-				let out_monkey = if v % div == 0 {
-					out_monkeys.0
-				} else { out_monkeys.1 };
-				out_list.push((out_monkey, v));
-			}
-
-			// Empty the list
-			for (target, val) in out_list {
-				monkeys.get_mut(target).unwrap()
-					.get_values_mut().push_back(val);
-			}
-		}
-		//println!("{:?}", monkeys);
-	}
-	let mut counts = monkeys.iter()
-		.map(Monkey::get_inspect_count)
-		.collect::<Vec<usize>>();
-	counts.sort_unstable();
-	counts.reverse();
-	counts.first().unwrap() * counts.get(1).unwrap()
-}
-
-#[derive(Debug)]
-struct Monkey {
-	items: VecDeque<usize>,
-	out_monkeys: (usize, usize),
-	oper: Operation,
-	cond: usize,
-	inspect_count: usize,
+	let mut troop = data.parse::<Troop>().unwrap()
+		.with_relief(Relief::Divide(3)).unwrap();
+	troop.run(20).unwrap();
+	troop.monkey_business()
 }
 
-#[derive(Debug,Copy,Clone)]
-enum Operand {
-	Val(usize),
+/// The value an operation is applied with
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Operand {
+	/// A fixed value
+	Val(u64),
+	/// The old worry level itself
 	Old
 }
 
-#[derive(Debug,Copy,Clone)]
-enum Operator {
+/// The operator of an operation
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Operator {
+	/// Addition
 	Add,
+	/// Multiplication
 	Mul,
+	/// Subtraction
 	Sub
 }
 
-#[derive(Debug,Copy,Clone)]
-struct Operation(Operator, Operand);
+/// The operation a monkey applies to the worry level of an item it inspects,
+/// as in `new = old * 19`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Operation {
+	/// The operator
+	pub operator: Operator,
+	/// The right hand side of the operation, the left one always being `old`
+	pub operand: Operand,
+}
 
 impl Operation {
-	const fn apply(&self, x: usize) -> usize {
-		match self.1 {
-			Operand::Old => {
-				match self.0 {
-					Operator::Add => x * 2,
-					Operator::Sub => 0,
-					Operator::Mul => x * x
-				}
-			},
-			Operand::Val(v) => {
-				match self.0 {
-					Operator::Add => x + v,
-					Operator::Sub => x - v,
-					Operator::Mul => x * v
-				}
-			}
+	/// Apply the operation to a worry level
+	///
+	/// # Arguments
+	///
+	///  - `old` : the worry level before the operation
+	///  - `modulus` : if set, the result is computed modulo this value, which
+	///    must not be zero
+	///
+	/// # Return value
+	///
+	/// Returns the new worry level, or `None` if it does not fit in a `u64`,
+	/// or would be negative. Subtracting never wraps around the modulus.
+	#[must_use]
+	pub fn apply(&self, old: u64, modulus: Option<u64>) -> Option<u64> {
+		let value = match self.operand {
+			Operand::Old => old,
+			Operand::Val(v) => v,
+		};
+		let Some(m) = modulus else {
+			return match self.operator {
+				Operator::Add => old.checked_add(value),
+				Operator::Mul => old.checked_mul(value),
+				Operator::Sub => old.checked_sub(value),
+			};
+		};
+		// Computing in u128 means that nothing can overflow before the
+		// remainder is taken
+		let (old, value, m) = (u128::from(old), u128::from(value), u128::from(m));
+		let new = match self.operator {
+			Operator::Add => old + value,
+			Operator::Mul => old * value,
+			Operator::Sub => old.checked_sub(value)?,
+		};
+		u64::try_from(new % m).ok()
+	}
+}
+
+impl std::str::FromStr for Operation {
+	type Err = String;
+	fn from_str(st: &str) -> Result<Self, Self::Err> {
+		let mut tokens = st.split_whitespace();
+		// The first operand is always "old"
+		if tokens.next() != Some("new") || tokens.next() != Some("=")
+			|| tokens.next() != Some("old") {
+			return Err(format!("Expected \"new = old\", found \"{st}\""));
+		}
+		let operator = match tokens.next() {
+			Some("+") => Operator::Add,
+			Some("-") => Operator::Sub,
+			Some("*") => Operator::Mul,
+			Some(x) => return Err(format!("Unknown operator \"{x}\"")),
+			None => return Err(String::from("Missing operator")),
+		};
+		let operand = match tokens.next() {
+			Some("old") => Operand::Old,
+			Some(ns) => Operand::Val(ns.parse::<u64>()
+				.map_err(|e| format!("Invalid number \"{ns}\": {e}"))?),
+			None => return Err(String::from("Missing operand")),
+		};
+		if let Some(x) = tokens.next() {
+			return Err(format!("Unexpected \"{x}\" after the operation"));
+		}
+		Ok(Self { operator, operand })
+	}
+}
+
+impl std::fmt::Display for Operation {
+	fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let operator = match self.operator {
+			Operator::Add => '+',
+			Operator::Mul => '*',
+			Operator::Sub => '-',
+		};
+		match self.operand {
+			Operand::Old => write!(fmt, "new = old {operator} old"),
+			Operand::Val(v) => write!(fmt, "new = old {operator} {v}"),
+		}
+	}
+}
+
+/// How the worry level of an item is relieved after a monkey inspects it
+#[derive(Debug, Copy, Clone)]
+pub enum Relief {
+	/// No relief at all, which lets the [`Troop`] keep worry levels modulo
+	/// the lcm of the divisors, unless a monkey subtracts
+	None,
+	/// The worry level is divided by the given value, rounded down
+	Divide(u64),
+	/// The worry level goes through a function, which returns `None` when it
+	/// cannot relieve it
+	Function(fn(u64) -> Option<u64>),
+}
+
+impl Default for Relief {
+	/// The puzzle's relief, dividing worry levels by three
+	fn default() -> Self {
+		Self::Divide(3)
+	}
+}
+
+impl Relief {
+	fn apply(self, worry: u64) -> Option<u64> {
+		match self {
+			Self::None => Some(worry),
+			Self::Divide(d) => worry.checked_div(d),
+			Self::Function(f) => f(worry),
 		}
 	}
 }
 
+/// A monkey, holding items and throwing them around
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Monkey {
+	// Items are tagged with an identifier to be followed through throws
+	items: VecDeque<(usize, u64)>,
+	operation: Operation,
+	divisor: u64,
+	targets: (usize, usize),
+	inspections: usize,
+}
+
 impl Monkey {
-	fn get_values_mut(&mut self) -> &mut VecDeque<usize> {
-		&mut self.items
+	/// Build a new monkey
+	///
+	/// # Arguments
+	///
+	///  - `items` : the worry levels of the items it starts with
+	///  - `operation` : the [`Operation`] applied to inspected items
+	///  - `divisor` : the value worry levels are tested to be divisible by
+	///  - `targets` : the monkeys items are thrown to when the test passes,
+	///    and when it fails
+	#[must_use]
+	pub fn new(items: Vec<u64>, operation: Operation, divisor: u64,
+			   targets: (usize, usize)) -> Self {
+		Self {
+			items: items.into_iter().enumerate().collect(),
+			operation,
+			divisor,
+			targets,
+			inspections: 0,
+		}
 	}
 
-	fn inspect_count_up(&mut self) {
-		self.inspect_count += self.items.len();
+	/// The worry levels of the items currently held, in order
+	pub fn items(&self) -> impl Iterator<Item = u64> + '_ {
+		self.items.iter().map(|&(_, worry)| worry)
 	}
 
-	const fn get_inspect_count(&self) -> usize {
-		self.inspect_count
+	/// The items currently held, in order, as pairs of an item identifier and
+	/// a worry level
+	///
+	/// In a [`Troop`], items are numbered from zero in the order they are
+	/// first held, monkey after monkey.
+	pub fn tagged_items(&self) -> impl Iterator<Item = (usize, u64)> + '_ {
+		self.items.iter().copied()
 	}
 
-	const fn get_op(&self) -> Operation {
-		self.oper
+	/// The operation applied to inspected items
+	#[must_use]
+	pub const fn operation(&self) -> Operation {
+		self.operation
 	}
 
-	const fn get_div(&self) -> usize {
-		self.cond
+	/// The value worry levels are tested to be divisible by
+	#[must_use]
+	pub const fn divisor(&self) -> u64 {
+		self.divisor
 	}
 
-	const fn get_out_monkeys(&self) -> (usize, usize) {
-		self.out_monkeys
+	/// The monkeys items are thrown to when the test passes, and when it
+	/// fails
+	#[must_use]
+	pub const fn targets(&self) -> (usize, usize) {
+		self.targets
+	}
+
+	/// How many items this monkey inspected so far
+	#[must_use]
+	pub const fn inspections(&self) -> usize {
+		self.inspections
 	}
 }
 
 impl std::str::FromStr for Monkey {
-	type Err = ();
+	type Err = String;
 	fn from_str(st: &str) -> Result<Self, Self::Err> {
-		// Alright, let's suffer now
-		let mut lines = st.split('\n').skip(1);
-		// The order will always be the same
-		let items: VecDeque<usize> = lines.next().unwrap()
-			.split(": ").nth(1).unwrap()
-			.split(", ")
-			.map(|s| s.parse::<usize>().unwrap())
-			.collect();
-		// The operation ***sucks***
-		let operation_str = lines.next().unwrap()
-			.split("new = ").nth(1).unwrap();
-		let mut operands = operation_str.split(' ').skip(1); // first one is
-															 // always "old"
-		let operator = match operands.next().unwrap() {
-			"+" => Operator::Add,
-			"-" => Operator::Sub,
-			"*" => Operator::Mul,
-			_ => unreachable!(),
-		};
-		let operand = match operands.next().unwrap() {
-			"old" => Operand::Old,
-			ns => {
-				let num = ns.parse::<usize>().unwrap();
-				Operand::Val(num)
+		parse_monkey(st).map(|(_, monkey)| monkey)
+	}
+}
+
+/// Store a field of a monkey, refusing duplicates
+fn set<T>(slot: &mut Option<T>, value: T, key: &str) -> Result<(), String> {
+	if slot.replace(value).is_some() {
+		return Err(format!("Duplicate \"{key}\" line"));
+	}
+	Ok(())
+}
+
+/// Parse the block of a monkey, whose lines can come in any order, along with
+/// the number in its header if there is one
+fn parse_monkey(st: &str) -> Result<(Option<usize>, Monkey), String> {
+	let number = |s: &str| s.trim().parse::<u64>()
+		.map_err(|e| format!("Invalid number \"{s}\": {e}"));
+	let monkey = |s: &str, prefix: &str| s.strip_prefix(prefix)
+		.ok_or_else(|| format!("Expected \"{prefix}\", found \"{s}\""))
+		.and_then(|s| s.trim().parse::<usize>()
+			.map_err(|e| format!("Invalid monkey \"{s}\": {e}")));
+
+	let mut header = None;
+	let mut items = None;
+	let mut operation = None;
+	let mut divisor = None;
+	let mut if_true = None;
+	let mut if_false = None;
+	for line in st.lines().map(str::trim).filter(|l| !l.is_empty()) {
+		let (key, value) = line.split_once(':')
+			.ok_or_else(|| format!("Invalid line \"{line}\""))?;
+		let value = value.trim();
+		match key {
+			"Starting items" => set(&mut items, if value.is_empty() {
+				Vec::new()
+			} else {
+				value.split(',').map(number).collect::<Result<Vec<u64>, String>>()?
+			}, key)?,
+			"Operation" => set(&mut operation, value.parse::<Operation>()?, key)?,
+			"Test" => {
+				let value = value.strip_prefix("divisible by")
+					.ok_or_else(|| format!("Unknown test \"{value}\""))?;
+				set(&mut divisor, number(value)?, key)?;
+			},
+			"If true" =>
+				set(&mut if_true, monkey(value, "throw to monkey")?, key)?,
+			"If false" =>
+				set(&mut if_false, monkey(value, "throw to monkey")?, key)?,
+			_ if key.starts_with("Monkey") =>
+				set(&mut header, monkey(key, "Monkey")?, "Monkey")?,
+			_ => return Err(format!("Unknown line \"{line}\"")),
+		}
+	}
+
+	let missing = |key: &str| format!("Missing \"{key}\" line");
+	Ok((header, Monkey::new(
+		items.ok_or_else(|| missing("Starting items"))?,
+		operation.ok_or_else(|| missing("Operation"))?,
+		divisor.ok_or_else(|| missing("Test"))?,
+		(if_true.ok_or_else(|| missing("If true"))?,
+			if_false.ok_or_else(|| missing("If false"))?),
+	)))
+}
+
+/// An item thrown by a monkey during a round
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Throw {
+	/// The round during which the item is thrown, starting from 1
+	pub round: usize,
+	/// The monkey throwing the item
+	pub monkey: usize,
+	/// The identifier of the item
+	pub item: usize,
+	/// The worry level when the monkey starts inspecting the item
+	pub before: u64,
+	/// The worry level after the monkey's operation
+	pub operated: u64,
+	/// The worry level after relief, with which the item is thrown
+	pub after: u64,
+	/// Whether the worry level passed the monkey's divisibility test
+	pub divisible: bool,
+	/// The monkey the item is thrown to
+	pub target: usize,
+}
+
+/// Something that happens while a [`Troop`] plays
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Event {
+	/// A monkey starts its turn
+	Turn {
+		/// The current round, starting from 1
+		round: usize,
+		/// The monkey whose turn it is
+		monkey: usize,
+	},
+	/// A monkey inspects and throws an item
+	Throw(Throw),
+	/// A round is over
+	RoundEnd {
+		/// The round that just ended, starting from 1
+		round: usize,
+		/// The worry levels of the items held by every monkey
+		holding: Vec<Vec<u64>>,
+		/// How many items every monkey inspected so far
+		inspections: Vec<usize>,
+	},
+}
+
+/// A troop of monkeys playing keep away with your items
+///
+/// After every inspection, the worry level goes through the [`Relief`] of the
+/// troop. When there is no relief and no monkey subtracts, worry levels are
+/// kept modulo the lcm of the divisors of all monkeys, which changes neither
+/// the tests nor the operations, so that any number of rounds can be
+/// simulated. Otherwise, the true worry levels are kept, and simulating fails
+/// when one of them overflows or goes below zero.
+#[derive(Debug, Clone)]
+pub struct Troop {
+	monkeys: Vec<Monkey>,
+	relief: Relief,
+	modulus: Option<u64>,
+	rounds: usize,
+}
+
+impl Troop {
+	/// Build a troop of monkeys, with the puzzle's default relief of dividing
+	/// worry levels by three
+	///
+	/// # Errors
+	///
+	/// Returns a `String` describing the problem if a divisor is zero, or if
+	/// a monkey throws to a monkey that does not exist, or to itself.
+	pub fn new(mut monkeys: Vec<Monkey>) -> Result<Self, String> {
+		for (idx, monkey) in monkeys.iter().enumerate() {
+			if monkey.divisor == 0 {
+				return Err(format!("Monkey {idx} tests divisibility by 0"));
+			}
+			for target in [monkey.targets.0, monkey.targets.1] {
+				if target >= monkeys.len() {
+					return Err(format!("Monkey {idx} throws to missing monkey {target}"));
+				} else if target == idx {
+					return Err(format!("Monkey {idx} throws to itself"));
+				}
 			}
+		}
+		// Number all items
+		monkeys.iter_mut()
+			.flat_map(|m| m.items.iter_mut())
+			.enumerate()
+			.for_each(|(id, item)| item.0 = id);
+		// A reduced worry level could go below zero where the true one does
+		// not, so subtracting rules out reducing. If the lcm does not fit,
+		// worry levels cannot be reduced anyway.
+		let subtracts = monkeys.iter()
+			.any(|m| m.operation.operator == Operator::Sub);
+		let modulus = monkeys.iter()
+			.try_fold(1_u64, |acc, m| acc.checked_mul(m.divisor / gcd(acc, m.divisor)))
+			.filter(|_| !subtracts);
+		Ok(Self { monkeys, relief: Relief::default(), modulus, rounds: 0 })
+	}
+
+	/// Change the relief applied after every inspection
+	///
+	/// The new relief applies from the next round on.
+	///
+	/// # Errors
+	///
+	/// Returns a `String` if the relief divides worry levels by zero.
+	pub fn with_relief(mut self, relief: Relief) -> Result<Self, String> {
+		if matches!(relief, Relief::Divide(0)) {
+			return Err(String::from("Relief cannot divide worry levels by 0"));
+		}
+		self.relief = relief;
+		Ok(self)
+	}
+
+	/// The relief applied after every inspection
+	#[must_use]
+	pub const fn relief(&self) -> Relief {
+		self.relief
+	}
+
+	/// The lcm of the divisors of all monkeys, if it fits in a `u64` and no
+	/// monkey subtracts, that is if worry levels can be reduced
+	#[must_use]
+	pub const fn modulus(&self) -> Option<u64> {
+		self.modulus
+	}
+
+	/// The monkeys of the troop
+	#[must_use]
+	pub fn monkeys(&self) -> &[Monkey] {
+		&self.monkeys
+	}
+
+	/// How many rounds were played so far
+	#[must_use]
+	pub const fn rounds(&self) -> usize {
+		self.rounds
+	}
+
+	/// Play one round : every monkey, in order, inspects and throws all of its
+	/// items
+	///
+	/// # Errors
+	///
+	/// Returns a `String` if a worry level overflows or goes below zero. The
+	/// troop is then left in the middle of the round.
+	pub fn round(&mut self) -> Result<(), String> {
+		self.round_with(|_| ())
+	}
+
+	/// Play one round, calling `f` on every [`Event`] of the round
+	///
+	/// # Errors
+	///
+	/// Returns a `String` if a worry level overflows or goes below zero, or if
+	/// a [`Relief::Function`] returns `None`. The troop is then left in the
+	/// middle of the round.
+	pub fn round_with<F>(&mut self, mut f: F) -> Result<(), String>
+		where F: FnMut(&Event) {
+		let round = self.rounds + 1;
+		// Reducing worry levels is only sound when nothing else touches them
+		let modulus = match self.relief {
+			Relief::None => self.modulus,
+			_ => None,
 		};
-		let operation = Operation(operator, operand);
-
-		// Div factor
-		let div_factor = lines.next().unwrap()
-			.split(" by ").nth(1).unwrap()
-			.parse::<usize>().unwrap();
-
-		// Monkeys
-		let monkey_true = lines.next().unwrap()
-			.split("monkey ").nth(1).unwrap()
-			.parse::<usize>().unwrap();
-		let monkey_false = lines.next().unwrap()
-			.split("monkey ").nth(1).unwrap()
-			.parse::<usize>().unwrap();
-
-		Ok(Self {
-			out_monkeys: (monkey_true, monkey_false),
-			items,
-			oper: operation,
-			cond: div_factor,
-			inspect_count: 0,
-		})
+		for idx in 0..self.monkeys.len() {
+			f(&Event::Turn { round, monkey: idx });
+			while let Some((item, before)) = self.monkeys[idx].items.pop_front() {
+				let monkey = &mut self.monkeys[idx];
+				monkey.inspections += 1;
+				let out_of_bounds = || format!(
+					"Monkey {idx}: worry level {before} went out of bounds in round {round}");
+				let operated = monkey.operation.apply(before, modulus)
+					.ok_or_else(out_of_bounds)?;
+				let after = self.relief.apply(operated)
+					.ok_or_else(out_of_bounds)?;
+				let divisible = after.is_multiple_of(monkey.divisor);
+				let target = if divisible {
+					monkey.targets.0
+				} else {
+					monkey.targets.1
+				};
+				f(&Event::Throw(Throw {
+					round,
+					monkey: idx,
+					item,
+					before,
+					operated,
+					after,
+					divisible,
+					target,
+				}));
+				self.monkeys[target].items.push_back((item, after));
+			}
+		}
+		self.rounds = round;
+		f(&Event::RoundEnd {
+			round,
+			holding: self.monkeys.iter()
+				.map(|m| m.items().collect())
+				.collect(),
+			inspections: self.inspections(),
+		});
+		Ok(())
+	}
+
+	/// Play several rounds
+	///
+	/// # Errors
+	///
+	/// Returns a `String` if a worry level overflows or goes below zero.
+	pub fn run(&mut self, rounds: usize) -> Result<(), String> {
+		(0..rounds).try_for_each(|_| self.round())
+	}
+
+	/// Play several rounds, calling `f` on every [`Event`]
+	///
+	/// # Errors
+	///
+	/// Returns a `String` if a worry level overflows or goes below zero.
+	pub fn run_with<F>(&mut self, rounds: usize, mut f: F) -> Result<(), String>
+		where F: FnMut(&Event) {
+		(0..rounds).try_for_each(|_| self.round_with(&mut f))
+	}
+
+	/// How many items every monkey inspected so far, in order
+	#[must_use]
+	pub fn inspections(&self) -> Vec<usize> {
+		self.monkeys.iter().map(Monkey::inspections).collect()
+	}
+
+	/// The level of monkey business : the product of the inspection counts of
+	/// the two most active monkeys
+	#[must_use]
+	pub fn monkey_business(&self) -> usize {
+		let mut counts = self.inspections();
+		counts.sort_unstable_by(|a, b| b.cmp(a));
+		counts.iter().take(2).product()
+	}
+}
+
+impl std::str::FromStr for Troop {
+	type Err = String;
+	fn from_str(st: &str) -> Result<Self, Self::Err> {
+		let monkeys = st.split("\n\n")
+			.filter(|block| !block.trim().is_empty())
+			.enumerate()
+			.map(|(idx, block)| match parse_monkey(block) {
+				Ok((Some(header), _)) if header != idx =>
+					Err(format!("Monkey {idx}: found header of monkey {header}")),
+				Ok((_, monkey)) => Ok(monkey),
+				Err(e) => Err(format!("Monkey {idx}: {e}")),
+			})
+			.collect::<Result<Vec<Monkey>, String>>()?;
+		Self::new(monkeys)
 	}
 }
 
+impl std::fmt::Display for Troop {
+	fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		for (idx, monkey) in self.monkeys.iter().enumerate() {
+			if idx > 0 {
+				writeln!(fmt)?;
+			}
+			let items = monkey.items()
+				.map(|w| w.to_string())
+				.collect::<Vec<String>>()
+				.join(", ");
+			writeln!(fmt, "Monkey {idx}:")?;
+			writeln!(fmt, "  Starting items: {items}")?;
+			writeln!(fmt, "  Operation: {}", monkey.operation)?;
+			writeln!(fmt, "  Test: divisible by {}", monkey.divisor)?;
+			writeln!(fmt, "    If true: throw to monkey {}", monkey.targets.0)?;
+			writeln!(fmt, "    If false: throw to monkey {}", monkey.targets.1)?;
+		}
+		Ok(())
+	}
+}
+
+const fn gcd(mut a: u64, mut b: u64) -> u64 {
+	while b != 0 {
+		(a, b) = (b, a % b);
+	}
+	a
+}
+
 /// Solve Advent of Code day 11 part two
 ///
 /// # Arguments
@@ -211,55 +621,11 @@ impl std::str::FromStr for Monkey {
 /// If any conversion assumed to be valid with the input fails, panics.
 #[must_use]
 pub fn solve_part_two(data: &str) -> usize {
-	let mut monkeys = data.split("\n\n")
-		.map(|st| st.parse::<Monkey>().unwrap())
-		.collect::<Vec<Monkey>>();
-	// So because we no longer divide by three, nothing prevents us from having
-	// the numbers explode.
-	// Well, nothing save for... modular arithmetic!
-	// Since all of the modular division factors are prime, we can take the
-	// multiple of all of them to figure out the smallest cycle of numbers we
-	// need to keep track of
-
-	let lcm: usize = monkeys.iter()
-		.map(Monkey::get_div)
-		.collect::<HashSet<usize>>()
-		.iter()
-		.product();
-
-	// We run 10000 cycles
-	for _ in 0..10000 {
-		for idx in 0..monkeys.len() {
-			let base_monkey = monkeys.get_mut(idx).unwrap();
-			let div = base_monkey.get_div();
-			let out_monkeys = base_monkey.get_out_monkeys();
-			let op = base_monkey.get_op();
-			base_monkey.inspect_count_up();
-			let items = base_monkey.get_values_mut();
-			// Index of where stuff ends up
-			let mut out_list: Vec<(usize, usize)> = Vec::new();
-			for _ in 0..items.len() {
-				let v = op.apply(items.pop_front().unwrap()) % lcm;
-				let out_monkey = if v % div == 0 {
-					out_monkeys.0
-				} else { out_monkeys.1 };
-				out_list.push((out_monkey, v));
-			}
-
-			// Empty the list
-			for (target, val) in out_list {
-				monkeys.get_mut(target).unwrap()
-					.get_values_mut().push_back(val);
-			}
-		}
-		//println!("{:?}", monkeys);
-	}
-	let mut counts = monkeys.iter()
-		.map(Monkey::get_inspect_count)
-		.collect::<Vec<usize>>();
-	counts.sort_unstable();
-	counts.reverse();
-	counts.first().unwrap() * counts.get(1).unwrap()
+	// Without relief, worry levels are kept modulo the lcm of the divisors
+	let mut troop = data.parse::<Troop>().unwrap()
+		.with_relief(Relief::None).unwrap();
+	troop.run(10000).unwrap();
+	troop.monkey_business()
 }
 
 // vim: set tw=80:
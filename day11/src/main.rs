@@ -3,8 +3,7 @@
 
 extern crate common;
 use common::read_data;
-mod lib;
-use lib::{solve_part_one, solve_part_two};
+use day11::{solve_part_one, solve_part_two};
 
 #[doc(hidden)]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -22,4 +21,162 @@ mod test {
 
 	test!(day11_01_example1, 1, 10605, "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old * 19\n  Test: divisible by 23\n    If true: throw to monkey 2\n    If false: throw to monkey 3\n\nMonkey 1:\n  Starting items: 54, 65, 75, 74\n  Operation: new = old + 6\n  Test: divisible by 19\n    If true: throw to monkey 2\n    If false: throw to monkey 0\n\nMonkey 2:\n  Starting items: 79, 60, 97\n  Operation: new = old * old\n  Test: divisible by 13\n    If true: throw to monkey 1\n    If false: throw to monkey 3\n\nMonkey 3:\n  Starting items: 74\n  Operation: new = old + 3\n  Test: divisible by 17\n    If true: throw to monkey 0\n    If false: throw to monkey 1");
 	test!(day11_02_example1, 2, 2713310158, "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old * 19\n  Test: divisible by 23\n    If true: throw to monkey 2\n    If false: throw to monkey 3\n\nMonkey 1:\n  Starting items: 54, 65, 75, 74\n  Operation: new = old + 6\n  Test: divisible by 19\n    If true: throw to monkey 2\n    If false: throw to monkey 0\n\nMonkey 2:\n  Starting items: 79, 60, 97\n  Operation: new = old * old\n  Test: divisible by 13\n    If true: throw to monkey 1\n    If false: throw to monkey 3\n\nMonkey 3:\n  Starting items: 74\n  Operation: new = old + 3\n  Test: divisible by 17\n    If true: throw to monkey 0\n    If false: throw to monkey 1");
+
+	const EXAMPLE: &str = "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old * 19\n  Test: divisible by 23\n    If true: throw to monkey 2\n    If false: throw to monkey 3\n\nMonkey 1:\n  Starting items: 54, 65, 75, 74\n  Operation: new = old + 6\n  Test: divisible by 19\n    If true: throw to monkey 2\n    If false: throw to monkey 0\n\nMonkey 2:\n  Starting items: 79, 60, 97\n  Operation: new = old * old\n  Test: divisible by 13\n    If true: throw to monkey 1\n    If false: throw to monkey 3\n\nMonkey 3:\n  Starting items: 74\n  Operation: new = old + 3\n  Test: divisible by 17\n    If true: throw to monkey 0\n    If false: throw to monkey 1";
+
+	#[test]
+	fn day11_troop() {
+		use day11::{Relief, Troop};
+		let mut troop = EXAMPLE.parse::<Troop>().unwrap();
+		assert_eq!(troop.modulus(), Some(96_577));
+		troop.run(1).unwrap();
+		let held = troop.monkeys().iter()
+			.map(|m| m.items().collect::<Vec<u64>>())
+			.collect::<Vec<_>>();
+		assert_eq!(held, vec![
+			vec![20, 23, 27, 26],
+			vec![2080, 25, 167, 207, 401, 1046],
+			vec![], vec![]]);
+
+		let mut troop = EXAMPLE.parse::<Troop>().unwrap().with_relief(Relief::None).unwrap();
+		troop.run(20).unwrap();
+		assert_eq!(troop.inspections(), vec![99, 97, 8, 103]);
+		assert_eq!(troop.rounds(), 20);
+
+		// Without reduction, the squares overflow quickly
+		let mut troop = EXAMPLE.parse::<Troop>().unwrap()
+			.with_relief(Relief::Function(|w| w.checked_add(1))).unwrap();
+		assert!(troop.run(100).unwrap_err().contains("out of bounds"));
+
+		// A relief that cannot relieve stops the troop
+		let mut troop = EXAMPLE.parse::<Troop>().unwrap()
+			.with_relief(Relief::Function(|w| w.checked_sub(1000))).unwrap();
+		assert_eq!(troop.round().unwrap_err(),
+			"Monkey 1: worry level 54 went out of bounds in round 1");
+	}
+
+	#[test]
+	fn day11_troop_subtraction() {
+		use day11::{Relief, Troop};
+		let data = "Monkey 0:\n  Starting items: 5\n  Operation: new = old - 7\n  Test: divisible by 2\n    If true: throw to monkey 1\n    If false: throw to monkey 1\n\nMonkey 1:\n  Starting items: 4\n  Operation: new = old - old\n  Test: divisible by 3\n    If true: throw to monkey 0\n    If false: throw to monkey 0";
+		let mut troop = data.parse::<Troop>().unwrap();
+		assert!(troop.round().is_err());
+
+		// Subtracting rules out reducing worry levels, 5 - 7 is still too low
+		let mut troop = data.parse::<Troop>().unwrap().with_relief(Relief::None).unwrap();
+		assert_eq!(troop.modulus(), None);
+		assert!(troop.round().unwrap_err().contains("out of bounds"));
+		let data = data.replace("old - 7", "old - 3");
+		let mut troop = data.parse::<Troop>().unwrap().with_relief(Relief::None).unwrap();
+		troop.round().unwrap();
+		assert_eq!(troop.monkeys()[0].items().collect::<Vec<u64>>(), vec![0, 0]);
+
+		assert_eq!(data.parse::<Troop>().unwrap().with_relief(Relief::Divide(0))
+			.unwrap_err(), "Relief cannot divide worry levels by 0");
+		assert!("Monkey 0:\n  Starting items: 1\n  Operation: new = old / 2\n  Test: divisible by 2\n    If true: throw to monkey 1\n    If false: throw to monkey 1"
+			.parse::<Troop>().unwrap_err().contains("Unknown operator"));
+	}
+
+	#[test]
+	fn day11_events() {
+		use day11::{Event, Troop};
+		let mut troop = EXAMPLE.parse::<Troop>().unwrap();
+		let mut path = Vec::new();
+		troop.run_with(3, |event| if let Event::Throw(t) = event {
+			if t.item == 0 {
+				path.push((t.round, t.monkey, t.before, t.after, t.target));
+			}
+		}).unwrap();
+		assert_eq!(path, vec![
+			(1, 0, 79, 500, 3), (1, 3, 500, 167, 1),
+			(2, 1, 167, 57, 2), (2, 2, 57, 1083, 3), (2, 3, 1083, 362, 1),
+			(3, 1, 362, 122, 0)]);
+	}
+
+	#[test]
+	fn day11_report() {
+		use day11::{report::report, Troop};
+		let mut troop = EXAMPLE.parse::<Troop>().unwrap();
+		let text = report(&mut troop, 2, 1).unwrap();
+		assert!(text.starts_with("\
+Monkey 0:
+  Monkey inspects an item with a worry level of 79.
+    Worry level is multiplied by 19 to 1501.
+    Monkey gets bored with item. Worry level is divided by 3 to 500.
+    Current worry level is not divisible by 23.
+    Item with worry level 500 is thrown to monkey 3.
+  Monkey inspects an item with a worry level of 98.
+    Worry level is multiplied by 19 to 1862.
+    Monkey gets bored with item. Worry level is divided by 3 to 620.
+    Current worry level is not divisible by 23.
+    Item with worry level 620 is thrown to monkey 3.
+Monkey 1:
+"));
+		assert!(text.contains("\
+Monkey 2:
+  Monkey inspects an item with a worry level of 79.
+    Worry level is multiplied by itself to 6241.
+    Monkey gets bored with item. Worry level is divided by 3 to 2080.
+    Current worry level is divisible by 13.
+    Item with worry level 2080 is thrown to monkey 1.
+"));
+		assert!(text.ends_with("\
+    Item with worry level 1046 is thrown to monkey 1.
+
+After round 1, the monkeys are holding items with these worry levels:
+Monkey 0: 20, 23, 27, 26
+Monkey 1: 2080, 25, 167, 207, 401, 1046
+Monkey 2: 
+Monkey 3: 
+
+After round 2, the monkeys are holding items with these worry levels:
+Monkey 0: 695, 10, 71, 135, 350
+Monkey 1: 43, 49, 58, 55, 362
+Monkey 2: 
+Monkey 3: 
+
+"));
+	}
+
+	#[test]
+	fn day11_round_trip() {
+		use day11::Troop;
+		let troop = EXAMPLE.parse::<Troop>().unwrap();
+		assert_eq!(troop.to_string().trim_end(), EXAMPLE);
+
+		// Lines can come in any order
+		let shuffled = "Monkey 0:\n  Test: divisible by 2\n  Operation: new = old + old\n    If false: throw to monkey 1\n  Starting items: 3\n    If true: throw to monkey 1\n\nMonkey 1:\n  Starting items:\n  Operation: new = old * 3\n  Test: divisible by 5\n    If true: throw to monkey 0\n    If false: throw to monkey 0\n";
+		let troop = shuffled.parse::<Troop>().unwrap();
+		assert_eq!(troop.to_string().parse::<Troop>().unwrap().monkeys(), troop.monkeys());
+		assert!(troop.to_string().starts_with("Monkey 0:\n  Starting items: 3\n  Operation: new = old + old\n"));
+
+		assert!(shuffled.replace("+ old", "% 2").parse::<Troop>().unwrap_err()
+			.contains("Unknown operator \"%\""));
+		assert!(shuffled.replace("  Starting items: 3\n", "").parse::<Troop>().unwrap_err()
+			.contains("Missing \"Starting items\" line"));
+		assert!(shuffled.replace("Monkey 1:", "Monkey 2:").parse::<Troop>().unwrap_err()
+			.contains("found header of monkey 2"));
+	}
+
+	#[test]
+	fn day11_generator() {
+		use day11::{generate::Generator, Relief, Troop};
+		let generator = Generator::new(2022).monkeys(10);
+		let troop = generator.generate().unwrap();
+		assert_eq!(troop.monkeys().len(), 10);
+		assert_eq!(generator.generate().unwrap().to_string(), troop.to_string());
+		assert_ne!(Generator::new(2023).monkeys(10).generate().unwrap().to_string(),
+			troop.to_string());
+
+		let parsed = troop.to_string().parse::<Troop>().unwrap();
+		assert_eq!(parsed.monkeys(), troop.monkeys());
+		assert_eq!(parsed.to_string(), troop.to_string());
+
+		let mut troop = troop.with_relief(Relief::None).unwrap();
+		troop.run(10000).unwrap();
+		assert!(troop.monkey_business() > 0);
+		assert!(Generator::new(0).monkeys(1).generate().is_err());
+		assert_eq!(Generator::new(0).monkeys(2).generate().unwrap().monkeys()[0].targets(),
+			(1, 1));
+	}
 }
@@ -0,0 +1,137 @@
+//! Text reports of the keep away game, in the puzzle's notation
+//!
+//! The [`Reporter`] turns the [`Event`]s of a [`Troop`] into the detailed
+//! turns and round summaries shown in the puzzle.
+
+use std::fmt::Write;
+
+use super::{Event, Operand, Operator, Relief, Throw, Troop};
+
+/// Builds a text report out of the events of a [`Troop`]
+#[derive(Debug, Clone)]
+pub struct Reporter {
+	operations: Vec<(Operator, Operand)>,
+	divisors: Vec<u64>,
+	relief: Relief,
+	details: usize,
+	text: String,
+}
+
+impl Reporter {
+	/// Build a reporter for the given troop
+	///
+	/// # Arguments
+	///
+	///  - `troop` : the [`Troop`] the events come from
+	///  - `details` : for how many rounds, from the first, every inspection is
+	///    described
+	#[must_use]
+	pub fn new(troop: &Troop, details: usize) -> Self {
+		Self {
+			operations: troop.monkeys().iter()
+				.map(|m| (m.operation().operator, m.operation().operand))
+				.collect(),
+			divisors: troop.monkeys().iter().map(super::Monkey::divisor).collect(),
+			relief: troop.relief(),
+			details,
+			text: String::new(),
+		}
+	}
+
+	/// Add an event to the report
+	pub fn push(&mut self, event: &Event) {
+		match event {
+			Event::Turn { round, monkey } if *round <= self.details => {
+				self.line(format_args!("Monkey {monkey}:"));
+			},
+			Event::Throw(throw) if throw.round <= self.details => {
+				self.throw(throw);
+			},
+			Event::RoundEnd { round, holding, .. } => {
+				if *round <= self.details {
+					self.text.push('\n');
+				}
+				self.line(format_args!("After round {round}, the monkeys are \
+					holding items with these worry levels:"));
+				for (monkey, items) in holding.iter().enumerate() {
+					let items = items.iter()
+						.map(u64::to_string)
+						.collect::<Vec<String>>()
+						.join(", ");
+					self.line(format_args!("Monkey {monkey}: {items}"));
+				}
+				self.text.push('\n');
+			},
+			_ => {},
+		}
+	}
+
+	/// The report so far
+	#[must_use]
+	pub fn text(&self) -> &str {
+		&self.text
+	}
+
+	/// Consume the reporter and return the report
+	#[must_use]
+	pub fn finish(self) -> String {
+		self.text
+	}
+
+	fn line(&mut self, args: std::fmt::Arguments<'_>) {
+		// Writing to a String never fails
+		let _ = self.text.write_fmt(args);
+		self.text.push('\n');
+	}
+
+	fn throw(&mut self, throw: &Throw) {
+		let Throw { monkey, before, operated, after, divisible, target, .. } =
+			*throw;
+		self.line(format_args!(
+			"  Monkey inspects an item with a worry level of {before}."));
+		let (operator, operand) = self.operations[monkey];
+		let operand = match operand {
+			Operand::Old => String::from("itself"),
+			Operand::Val(v) => v.to_string(),
+		};
+		let verb = match operator {
+			Operator::Add => "increases by",
+			Operator::Mul => "is multiplied by",
+			Operator::Sub => "decreases by",
+		};
+		self.line(format_args!(
+			"    Worry level {verb} {operand} to {operated}."));
+		match self.relief {
+			Relief::None => {},
+			Relief::Divide(d) => self.line(format_args!("    Monkey gets bored \
+				with item. Worry level is divided by {d} to {after}.")),
+			Relief::Function(_) => self.line(format_args!("    Monkey gets \
+				bored with item. Worry level is relieved to {after}.")),
+		}
+		let not = if divisible { "" } else { "not " };
+		let divisor = self.divisors[monkey];
+		self.line(format_args!(
+			"    Current worry level is {not}divisible by {divisor}."));
+		self.line(format_args!(
+			"    Item with worry level {after} is thrown to monkey {target}."));
+	}
+}
+
+/// Play several rounds and report them in the puzzle's notation
+///
+/// # Arguments
+///
+///  - `troop` : the [`Troop`] playing
+///  - `rounds` : how many rounds are played
+///  - `details` : for how many rounds, from the first, every inspection is
+///    described
+///
+/// # Errors
+///
+/// Returns a `String` if a worry level overflows or goes below zero.
+pub fn report(troop: &mut Troop, rounds: usize, details: usize)
+	-> Result<String, String> {
+	let mut reporter = Reporter::new(troop, details);
+	troop.run_with(rounds, |event| reporter.push(event))?;
+	Ok(reporter.finish())
+}
@@ -14,16 +14,19 @@
 #![deny(rustdoc::missing_crate_level_docs)]
 
 use std::{
-	cmp::{
-		Eq,
-		Ordering,
-		PartialEq,
-		PartialOrd,
-	},
-	collections::VecDeque,
+	borrow::Borrow,
+	cmp::Ordering,
+	fmt,
 	iter::zip,
+	str::FromStr,
 };
 
+use serde_json::Value;
+
+/// How deep lists may nest when parsing or converting a packet, as with
+/// `serde_json`, so that comparing packets cannot overflow the stack
+pub const MAX_DEPTH: usize = 128;
+
 /// Solve Advent of Code day 13 part one
 ///
 /// # Arguments
@@ -43,7 +46,7 @@ pub fn solve_part_one(data: &str) -> usize {
 	data.split("\n\n")
 		.enumerate()
 		.filter_map(|(idx, st)| {
-			let packets: Vec<Packet> = st.split('\n')
+			let packets: Vec<Packet> = st.lines()
 				.map(|x| x.parse::<Packet>().unwrap()).collect::<Vec<_>>();
 			if packets[0] < packets[1] {
 				Some(idx+1)
@@ -54,29 +57,18 @@ pub fn solve_part_one(data: &str) -> usize {
 	.sum()
 }
 
+/// An item of a [`Packet`] : either an integer or a list
 #[derive(Debug, Clone)]
-enum PacketItem {
+pub enum PacketItem {
+	/// An integer
 	Int(usize),
+	/// A list, itself a packet
 	Packet(Packet)
 }
 
 impl PartialEq for PacketItem {
 	fn eq(&self, other: &Self) -> bool {
-		match (self, other) {
-			// If both are numbers, compare
-			(Self::Int(a), Self::Int(b)) => a == b,
-			// If both are packets, compare the packets
-			(Self::Packet(a), Self::Packet(b)) => a == b,
-			// If only one is an Int, create a packet with only it and compare
-			(Self::Packet(a), Self::Int(b)) => {
-				let pb = Packet::from_int(*b);
-				*a == pb
-			},
-			(Self::Int(a), Self::Packet(b)) => {
-				let pa = Packet::from_int(*a);
-				pa == *b
-			}
-		}
+		self.cmp(other) == Ordering::Equal
 	}
 }
 
@@ -84,42 +76,97 @@ impl Eq for PacketItem {}
 
 impl PartialOrd for PacketItem {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for PacketItem {
+	fn cmp(&self, other: &Self) -> Ordering {
 		match (self, other) {
-			(Self::Int(a), Self::Int(b)) => a.partial_cmp(b),
-			(Self::Packet(a), Self::Packet(b)) => a.partial_cmp(b),
-			(Self::Packet(a), Self::Int(b)) => {
-				let pb = Packet::from_int(*b);
-				a.partial_cmp(&pb)
-			},
-			(Self::Int(a), Self::Packet(b)) => {
-				let pa = Packet::from_int(*a);
-				pa.partial_cmp(b)
-			}
+			// If both are numbers, compare
+			(Self::Int(a), Self::Int(b)) => a.cmp(b),
+			// If both are packets, compare the packets
+			(Self::Packet(a), Self::Packet(b)) => a.cmp(b),
+			// If only one is an Int, compare as if it was alone in a list
+			(Self::Packet(a), Self::Int(_)) =>
+				cmp_lists(&a.data, std::slice::from_ref(other)),
+			(Self::Int(_), Self::Packet(b)) =>
+				cmp_lists(std::slice::from_ref(self), &b.data),
 		}
 	}
 }
 
-#[derive(Debug, Clone)]
-struct Packet {
+impl fmt::Display for PacketItem {
+	fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Int(a) => write!(fmt, "{a}"),
+			Self::Packet(a) => write!(fmt, "{a}"),
+		}
+	}
+}
+
+fn cmp_lists(left: &[PacketItem], right: &[PacketItem]) -> Ordering {
+	zip(left, right)
+		.map(|(a, b)| a.cmp(b))
+		.find(|o| o.is_ne())
+		// If we get here, either side has run out, or we're equal
+		// So the result is just a size comparison
+		.unwrap_or_else(|| left.len().cmp(&right.len()))
+}
+
+/// A distress signal packet : a list of integers and lists
+///
+/// Packets are ordered as described in the puzzle, which means that packets
+/// like `[[1]]` and `[1]` are equal even though they are written differently.
+///
+/// They are parsed from and written to the puzzle's notation, which is also
+/// valid JSON, and can be converted from and to a [`serde_json::Value`].
+/// Both refuse lists nested more than [`MAX_DEPTH`] levels deep.
+#[derive(Debug, Clone, Default)]
+pub struct Packet {
 	data: Vec<PacketItem>
 }
 
 impl Packet {
-	fn from_int(val: usize) -> Self {
+	/// Build a packet from its items
+	#[must_use]
+	pub const fn new(data: Vec<PacketItem>) -> Self {
+		Self { data }
+	}
+
+	/// Build a packet holding a single integer
+	#[must_use]
+	pub fn from_int(val: usize) -> Self {
 		Self { data: vec![PacketItem::Int(val)] }
 	}
 
-	fn from_packet(pa: Self) -> Self {
+	/// Build a packet holding a single packet
+	#[must_use]
+	pub fn from_packet(pa: Self) -> Self {
 		Self { data: vec![PacketItem::Packet(pa)] }
 	}
+
+	/// The items of the packet
+	#[must_use]
+	pub fn items(&self) -> &[PacketItem] {
+		&self.data
+	}
+
+	/// Compare two packets, recording every step of the comparison
+	///
+	/// The returned [`Trace`] holds the same ordering as [`Ord::cmp`], and
+	/// renders as the indented walkthrough of the puzzle.
+	#[must_use]
+	pub fn compare_with_trace(&self, other: &Self) -> Trace {
+		let mut steps = Vec::new();
+		let ordering = trace_packets(self, other, 0, &mut steps);
+		Trace { ordering, steps }
+	}
 }
 
 impl PartialEq for Packet {
 	fn eq(&self, other: &Self) -> bool {
-		// Compare lengths
-		self.data.len() == other.data.len()
-			&& zip(&self.data, &other.data)
-				.all(|(a, b)| a == b)
+		self.cmp(other) == Ordering::Equal
 	}
 }
 
@@ -127,103 +174,435 @@ impl Eq for Packet {}
 
 impl PartialOrd for Packet {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-		// We're not actually going to reuse the PartialEq/Eq implementations,
-		// because using them individually is much less efficient
-		let mut stack: VecDeque<(&PacketItem, &PacketItem)> = zip(&self.data, &other.data).collect();
-		while !stack.is_empty() {
-			let (left, right) = stack.pop_front().unwrap();
-			match (left, right) {
-				(PacketItem::Int(a), PacketItem::Int(b)) => {
-					if a == b {
-						continue;
-					}
-					return a.partial_cmp(b);
-				},
-				(PacketItem::Packet(a), PacketItem::Packet(b)) => {
-					// Input shouldn't be too bad...
-					if a == b {
-						continue;
-					}
-					return a.partial_cmp(b);
-				},
-				(PacketItem::Packet(pa), PacketItem::Int(b)) => {
-					let pb = Self::from_int(*b);
-					if *pa == pb {
-						continue;
-					}
-					return pa.partial_cmp(&pb);
-				},
-				(PacketItem::Int(a), PacketItem::Packet(pb)) => {
-					let pa = Self::from_int(*a);
-					if pa == *pb {
-						continue;
-					}
-					return pa.partial_cmp(pb);
-				}
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for Packet {
+	fn cmp(&self, other: &Self) -> Ordering {
+		cmp_lists(&self.data, &other.data)
+	}
+}
+
+impl fmt::Display for Packet {
+	fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(fmt, "[")?;
+		for (idx, item) in self.data.iter().enumerate() {
+			if idx > 0 {
+				write!(fmt, ",")?;
 			}
+			write!(fmt, "{item}")?;
 		}
+		write!(fmt, "]")
+	}
+}
 
-		// If we get here, either side has run out, or we're equal
-		// So the result is just a size comparison
-		self.data.len().partial_cmp(&other.data.len())
+/// One side of a comparison
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Side {
+	/// The left packet
+	Left,
+	/// The right packet
+	Right,
+}
+
+impl fmt::Display for Side {
+	fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Left => write!(fmt, "left"),
+			Self::Right => write!(fmt, "right"),
+		}
 	}
 }
 
-impl Ord for Packet {
-	fn cmp(&self, other: &Self) -> Ordering {
-		self.partial_cmp(other).unwrap()
+/// What happened during one step of a packet comparison
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceEvent {
+	/// Two items are compared
+	Compare(PacketItem, PacketItem),
+	/// An integer on the given side is converted to a list holding it
+	Promote(Side, usize),
+	/// Two integers differ, the given side holding the smaller one
+	Smaller(Side),
+	/// The given side ran out of items first
+	RanOut(Side),
+}
+
+/// A step of a packet comparison, along with its nesting depth
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStep {
+	/// How deep in the comparison this step happens
+	pub depth: usize,
+	/// What happens
+	pub event: TraceEvent,
+}
+
+impl fmt::Display for TraceStep {
+	fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(fmt, "{}- ", "  ".repeat(self.depth))?;
+		let verdict = |side: Side| if side == Side::Left {
+			"so inputs are in the right order"
+		} else {
+			"so inputs are not in the right order"
+		};
+		match &self.event {
+			TraceEvent::Compare(a, b) => write!(fmt, "Compare {a} vs {b}"),
+			TraceEvent::Promote(side, val) => write!(fmt,
+				"Mixed types; convert {side} to [{val}] and retry comparison"),
+			TraceEvent::Smaller(side) => write!(fmt,
+				"{} side is smaller, {}", capitalize(*side), verdict(*side)),
+			TraceEvent::RanOut(side) => write!(fmt,
+				"{} side ran out of items, {}", capitalize(*side), verdict(*side)),
+		}
 	}
 }
 
-impl std::str::FromStr for Packet {
-	type Err = ();
-	fn from_str(st: &str) -> Result<Self, Self::Err> {
-		// Packet should have `[` and `]`
-		let mut st_it = st.chars();
-		st_it.next();
-		st_it.next_back();
-		let mut data: Vec<PacketItem> = Vec::new();
-		let mut digit_buffer = String::new();
-
-		// We'll now eat st_it
-		while let Some(c) = st_it.next() {
-			if c.is_ascii_digit() {
-				// append to current digit_buffer
-				digit_buffer.push(c);
-			} else if c == ',' && !digit_buffer.is_empty() {
-				// Terminate the digit buffer
-				let number = digit_buffer.parse::<usize>().unwrap();
-				data.push(PacketItem::Int(number));
-				digit_buffer.clear();
-			} else if c == '[' {
-				// Thal help us
-				// Collect the entire underlying list in a string, parse that,
-				// and then repeat
-				let mut packet_buffer: String = "[".into();
-				let mut bracket_count = 1;
-				while bracket_count > 0 {
-					let k = st_it.next().expect("No next");
-					if k == '[' {
-						bracket_count += 1;
-					} else if k == ']' {
-						bracket_count -= 1;
-					}
-					packet_buffer.push(k);
-				}
-				let pa = packet_buffer.parse::<Self>().unwrap();
-				data.push(PacketItem::Packet(pa));
+const fn capitalize(side: Side) -> &'static str {
+	match side {
+		Side::Left => "Left",
+		Side::Right => "Right",
+	}
+}
+
+/// The recorded steps of a packet comparison, see
+/// [`Packet::compare_with_trace`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+	ordering: Ordering,
+	steps: Vec<TraceStep>,
+}
+
+impl Trace {
+	/// The result of the comparison
+	#[must_use]
+	pub const fn ordering(&self) -> Ordering {
+		self.ordering
+	}
+
+	/// Whether the packets are in the right order
+	#[must_use]
+	pub const fn in_order(&self) -> bool {
+		self.ordering.is_lt()
+	}
+
+	/// Every step of the comparison, in order
+	#[must_use]
+	pub fn steps(&self) -> &[TraceStep] {
+		&self.steps
+	}
+}
+
+impl fmt::Display for Trace {
+	fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (idx, step) in self.steps.iter().enumerate() {
+			if idx > 0 {
+				writeln!(fmt)?;
+			}
+			write!(fmt, "{step}")?;
+		}
+		Ok(())
+	}
+}
+
+fn trace_packets(left: &Packet, right: &Packet, depth: usize,
+				 steps: &mut Vec<TraceStep>) -> Ordering {
+	steps.push(TraceStep { depth, event: TraceEvent::Compare(
+		PacketItem::Packet(left.clone()), PacketItem::Packet(right.clone())) });
+	for (a, b) in zip(&left.data, &right.data) {
+		let ordering = trace_items(a, b, depth + 1, steps);
+		if ordering.is_ne() {
+			return ordering;
+		}
+	}
+	let ordering = left.data.len().cmp(&right.data.len());
+	let event = match ordering {
+		Ordering::Less => TraceEvent::RanOut(Side::Left),
+		Ordering::Greater => TraceEvent::RanOut(Side::Right),
+		Ordering::Equal => return ordering,
+	};
+	steps.push(TraceStep { depth: depth + 1, event });
+	ordering
+}
+
+fn trace_items(left: &PacketItem, right: &PacketItem, depth: usize,
+			   steps: &mut Vec<TraceStep>) -> Ordering {
+	match (left, right) {
+		(PacketItem::Int(a), PacketItem::Int(b)) => {
+			steps.push(TraceStep { depth,
+				event: TraceEvent::Compare(left.clone(), right.clone()) });
+			let ordering = a.cmp(b);
+			let event = match ordering {
+				Ordering::Less => TraceEvent::Smaller(Side::Left),
+				Ordering::Greater => TraceEvent::Smaller(Side::Right),
+				Ordering::Equal => return ordering,
+			};
+			steps.push(TraceStep { depth: depth + 1, event });
+			ordering
+		},
+		(PacketItem::Packet(a), PacketItem::Packet(b)) =>
+			trace_packets(a, b, depth, steps),
+		(PacketItem::Int(a), PacketItem::Packet(b)) => {
+			steps.push(TraceStep { depth,
+				event: TraceEvent::Compare(left.clone(), right.clone()) });
+			steps.push(TraceStep { depth: depth + 1,
+				event: TraceEvent::Promote(Side::Left, *a) });
+			trace_packets(&Packet::from_int(*a), b, depth + 1, steps)
+		},
+		(PacketItem::Packet(a), PacketItem::Int(b)) => {
+			steps.push(TraceStep { depth,
+				event: TraceEvent::Compare(left.clone(), right.clone()) });
+			steps.push(TraceStep { depth: depth + 1,
+				event: TraceEvent::Promote(Side::Right, *b) });
+			trace_packets(a, &Packet::from_int(*b), depth + 1, steps)
+		},
+	}
+}
+
+/// The error returned when a [`Packet`] cannot be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePacketError {
+	position: usize,
+	message: String,
+}
+
+impl ParsePacketError {
+	fn new(position: usize, message: impl Into<String>) -> Self {
+		Self { position, message: message.into() }
+	}
+
+	/// The position in the input, in bytes, where parsing failed
+	#[must_use]
+	pub const fn position(&self) -> usize {
+		self.position
+	}
+}
+
+impl fmt::Display for ParsePacketError {
+	fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(fmt, "{} at position {}", self.message, self.position)
+	}
+}
+
+impl std::error::Error for ParsePacketError {}
+
+/// A recursive descent parser over the bytes of a packet
+struct Parser<'a> {
+	input: &'a [u8],
+	position: usize,
+	depth: usize,
+}
+
+impl Parser<'_> {
+	fn skip_whitespace(&mut self) {
+		while self.input.get(self.position)
+			.is_some_and(u8::is_ascii_whitespace) {
+			self.position += 1;
+		}
+	}
+
+	fn peek(&mut self) -> Option<u8> {
+		self.skip_whitespace();
+		self.input.get(self.position).copied()
+	}
+
+	fn unexpected(&self) -> ParsePacketError {
+		match self.input.get(self.position) {
+			Some(&c) => ParsePacketError::new(self.position,
+				format!("Unexpected character '{}'", char::from(c))),
+			None => ParsePacketError::new(self.position, "Unexpected end of input"),
+		}
+	}
+
+	fn expect(&mut self, expected: u8) -> Result<(), ParsePacketError> {
+		if self.peek() == Some(expected) {
+			self.position += 1;
+			Ok(())
+		} else {
+			Err(self.unexpected())
+		}
+	}
+
+	fn packet(&mut self) -> Result<Packet, ParsePacketError> {
+		self.expect(b'[')?;
+		if self.depth == MAX_DEPTH {
+			return Err(ParsePacketError::new(self.position - 1,
+				format!("Lists nest deeper than {MAX_DEPTH} levels")));
+		}
+		self.depth += 1;
+		let packet = self.list();
+		self.depth -= 1;
+		packet
+	}
+
+	/// The items of a list, the opening bracket being already read
+	fn list(&mut self) -> Result<Packet, ParsePacketError> {
+		let mut data = Vec::new();
+		if self.peek() == Some(b']') {
+			self.position += 1;
+			return Ok(Packet { data });
+		}
+		loop {
+			data.push(self.item()?);
+			match self.peek() {
+				Some(b',') => self.position += 1,
+				Some(b']') => {
+					self.position += 1;
+					return Ok(Packet { data });
+				},
+				_ => return Err(self.unexpected()),
 			}
 		}
+	}
+
+	fn item(&mut self) -> Result<PacketItem, ParsePacketError> {
+		match self.peek() {
+			Some(b'[') => Ok(PacketItem::Packet(self.packet()?)),
+			Some(c) if c.is_ascii_digit() => {
+				let start = self.position;
+				while self.input.get(self.position).is_some_and(u8::is_ascii_digit) {
+					self.position += 1;
+				}
+				std::str::from_utf8(&self.input[start..self.position])
+					.expect("Digits are UTF-8")
+					.parse::<usize>()
+					.map(PacketItem::Int)
+					.map_err(|e| ParsePacketError::new(start, e.to_string()))
+			},
+			_ => Err(self.unexpected()),
+		}
+	}
+}
 
-		if !digit_buffer.is_empty() {
-			let number = digit_buffer.parse::<usize>().unwrap();
-			data.push(PacketItem::Int(number));
+impl FromStr for Packet {
+	type Err = ParsePacketError;
+	fn from_str(st: &str) -> Result<Self, Self::Err> {
+		let mut parser = Parser { input: st.as_bytes(), position: 0, depth: 0 };
+		let packet = parser.packet()?;
+		// Nothing may follow the packet
+		if parser.peek().is_some() {
+			return Err(parser.unexpected());
 		}
+		Ok(packet)
+	}
+}
+
+impl From<&Packet> for Value {
+	fn from(packet: &Packet) -> Self {
+		Self::Array(packet.data.iter()
+			.map(|item| match item {
+				PacketItem::Int(a) => Self::from(*a),
+				PacketItem::Packet(a) => Self::from(a),
+			})
+			.collect())
+	}
+}
+
+impl From<Packet> for Value {
+	fn from(packet: Packet) -> Self {
+		Self::from(&packet)
+	}
+}
 
+impl Packet {
+	/// Convert a list nested `depth` levels deep
+	fn from_value(value: &Value, depth: usize) -> Result<Self, String> {
+		let Value::Array(items) = value else {
+			return Err(format!("Expected a list, found {value}"));
+		};
+		if depth == MAX_DEPTH {
+			return Err(format!("Lists nest deeper than {MAX_DEPTH} levels"));
+		}
+		let data = items.iter()
+			.map(|item| match item {
+				Value::Array(_) =>
+					Ok(PacketItem::Packet(Self::from_value(item, depth + 1)?)),
+				Value::Number(n) => n.as_u64()
+					.and_then(|n| usize::try_from(n).ok())
+					.map(PacketItem::Int)
+					.ok_or_else(|| format!("Expected a positive integer, found {n}")),
+				x => Err(format!("Expected an integer or a list, found {x}")),
+			})
+			.collect::<Result<Vec<PacketItem>, String>>()?;
 		Ok(Self { data })
 	}
 }
 
+impl TryFrom<&Value> for Packet {
+	type Error = String;
+	fn try_from(value: &Value) -> Result<Self, Self::Error> {
+		Self::from_value(value, 0)
+	}
+}
+
+impl TryFrom<Value> for Packet {
+	type Error = String;
+	fn try_from(value: Value) -> Result<Self, Self::Error> {
+		Self::try_from(&value)
+	}
+}
+
+/// Parse every packet of the puzzle input, in order, ignoring blank lines
+///
+/// # Errors
+///
+/// If a packet cannot be parsed, returns a `String` naming the line and the
+/// position of the error.
+pub fn parse_packets(data: &str) -> Result<Vec<Packet>, String> {
+	data.lines()
+		.enumerate()
+		.filter(|(_, line)| !line.trim().is_empty())
+		.map(|(idx, line)| line.parse::<Packet>()
+			.map_err(|e| format!("Line {}: {e}", idx + 1)))
+		.collect()
+}
+
+/// Find the 1-based positions the dividers would take in the sorted list of
+/// packets and dividers
+///
+/// Rather than sorting, every packet is compared once with every divider, so
+/// this runs in O(n) for a fixed number of dividers, and the packets can be
+/// streamed without ever being collected. Like the first match of a sorted
+/// list, a divider equal to other packets takes the first position among them.
+///
+/// # Arguments
+///
+///  - `packets` : the packets, owned or borrowed
+///  - `dividers` : the divider packets
+///
+/// # Return value
+///
+/// Returns the position of every divider, in the order of `dividers`.
+pub fn divider_positions<I>(packets: I, dividers: &[Packet]) -> Vec<usize>
+	where I: IntoIterator, I::Item: Borrow<Packet> {
+	// Each divider comes after the dividers smaller than itself
+	let mut positions = dividers.iter()
+		.map(|divider| 1 + dividers.iter().filter(|d| *d < divider).count())
+		.collect::<Vec<usize>>();
+	for packet in packets {
+		let packet = packet.borrow();
+		for (position, divider) in positions.iter_mut().zip(dividers) {
+			if packet < divider {
+				*position += 1;
+			}
+		}
+	}
+	positions
+}
+
+/// Sort all the packets of the puzzle input along with the dividers
+///
+/// # Errors
+///
+/// If a packet cannot be parsed, returns a `String` naming the line and the
+/// position of the error.
+pub fn sorted_packets(data: &str, dividers: &[Packet])
+	-> Result<Vec<Packet>, String> {
+	let mut packets = parse_packets(data)?;
+	packets.extend_from_slice(dividers);
+	packets.sort();
+	Ok(packets)
+}
+
 /// Solve Advent of Code day 13 part two
 ///
 /// # Arguments
@@ -239,23 +618,15 @@ impl std::str::FromStr for Packet {
 ///
 /// If any conversion assumed to be valid with the input fails, panics.
 #[must_use]
-#[allow(clippy::missing_const_for_fn)]
 pub fn solve_part_two(data: &str) -> usize {
-	let mut packets = data.split('\n')
-		.filter_map(|x| {
-			if x.is_empty() {
-				None
-			} else { Some(x.parse::<Packet>().unwrap()) }
-		}).collect::<Vec<Packet>>();
-	let segment_one = Packet::from_packet(Packet::from_int(2));
-	let segment_two = Packet::from_packet(Packet::from_int(6));
-
-	packets.push(segment_one.clone());
-	packets.push(segment_two.clone());
-	packets.sort_unstable();
-	let mut packets_it = packets.iter();
-	(packets_it.clone().position(|s| *s == segment_one).expect("One") + 1) *
-		(packets_it.position(|s| *s == segment_two).expect("Two") + 1)
+	let packets = data.lines()
+		.filter(|x| !x.is_empty())
+		.map(|x| x.parse::<Packet>().unwrap());
+	let dividers = [
+		Packet::from_packet(Packet::from_int(2)),
+		Packet::from_packet(Packet::from_int(6)),
+	];
+	divider_positions(packets, &dividers).iter().product()
 }
 
 // vim: set tw=80:
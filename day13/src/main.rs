@@ -3,8 +3,7 @@
 
 extern crate common;
 use common::read_data;
-mod lib;
-use lib::{solve_part_one, solve_part_two};
+use day13::{solve_part_one, solve_part_two};
 
 #[doc(hidden)]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -22,4 +21,108 @@ mod test {
 
 	test!(day13_01_example1, 1, 13, "[1,1,3,1,1]\n[1,1,5,1,1]\n\n[[1],[2,3,4]]\n[[1],4]\n\n[9]\n[[8,7,6]]\n\n[[4,4],4,4]\n[[4,4],4,4,4]\n\n[7,7,7,7]\n[7,7,7]\n\n[]\n[3]\n\n[[[]]]\n[[]]\n\n[1,[2,[3,[4,[5,6,7]]]],8,9]\n[1,[2,[3,[4,[5,6,0]]]],8,9]");
 	test!(day13_02_example1, 2, 140, "[1,1,3,1,1]\n[1,1,5,1,1]\n\n[[1],[2,3,4]]\n[[1],4]\n\n[9]\n[[8,7,6]]\n\n[[4,4],4,4]\n[[4,4],4,4,4]\n\n[7,7,7,7]\n[7,7,7]\n\n[]\n[3]\n\n[[[]]]\n[[]]\n\n[1,[2,[3,[4,[5,6,7]]]],8,9]\n[1,[2,[3,[4,[5,6,0]]]],8,9]");
+
+	#[test]
+	fn day13_packet_round_trip() {
+		use day13::Packet;
+		let packet: Packet = " [1, [2,[3 ,[]]], 10]".parse().unwrap();
+		assert_eq!(packet.to_string(), "[1,[2,[3,[]]],10]");
+		assert_eq!(packet.to_string().parse::<Packet>().unwrap().to_string(),
+			packet.to_string());
+		assert_eq!("[[1]]".parse::<Packet>().unwrap(), "[1]".parse().unwrap());
+
+		let err = "[1,[2,x]]".parse::<Packet>().unwrap_err();
+		assert_eq!(err.position(), 6);
+		assert_eq!(err.to_string(), "Unexpected character 'x' at position 6");
+		assert_eq!("[1,[2]".parse::<Packet>().unwrap_err().position(), 6);
+		assert_eq!("[1]]".parse::<Packet>().unwrap_err().position(), 3);
+	}
+
+	#[test]
+	fn day13_packet_json() {
+		use day13::Packet;
+		let packet: Packet = "[[4,4],4,[]]".parse().unwrap();
+		let value = serde_json::Value::from(&packet);
+		assert_eq!(value, serde_json::json!([[4, 4], 4, []]));
+		assert_eq!(Packet::try_from(value).unwrap().to_string(), "[[4,4],4,[]]");
+		assert!(Packet::try_from(serde_json::json!([1, -2])).is_err());
+		assert!(Packet::try_from(serde_json::json!(3)).is_err());
+	}
+
+	#[test]
+	fn day13_compare_with_trace() {
+		use day13::Packet;
+		let trace = |a: &str, b: &str| a.parse::<Packet>().unwrap()
+			.compare_with_trace(&b.parse().unwrap());
+
+		let pair = trace("[[1],[2,3,4]]", "[[1],4]");
+		assert!(pair.in_order());
+		assert_eq!(pair.to_string(), "\
+- Compare [[1],[2,3,4]] vs [[1],4]
+  - Compare [1] vs [1]
+    - Compare 1 vs 1
+  - Compare [2,3,4] vs 4
+    - Mixed types; convert right to [4] and retry comparison
+    - Compare [2,3,4] vs [4]
+      - Compare 2 vs 4
+        - Left side is smaller, so inputs are in the right order");
+
+		let pair = trace("[9]", "[[8,7,6]]");
+		assert!(!pair.in_order());
+		assert_eq!(pair.to_string(), "\
+- Compare [9] vs [[8,7,6]]
+  - Compare 9 vs [8,7,6]
+    - Mixed types; convert left to [9] and retry comparison
+    - Compare [9] vs [8,7,6]
+      - Compare 9 vs 8
+        - Right side is smaller, so inputs are not in the right order");
+
+		let pair = trace("[[[]]]", "[[]]");
+		assert_eq!(pair.ordering(), std::cmp::Ordering::Greater);
+		assert_eq!(pair.to_string(), "\
+- Compare [[[]]] vs [[]]
+  - Compare [[]] vs []
+    - Right side ran out of items, so inputs are not in the right order");
+	}
+
+	#[test]
+	fn day13_divider_positions() {
+		use day13::{divider_positions, sorted_packets, Packet};
+		let data = "[1,1,3,1,1]\n[1,1,5,1,1]\n\n[[1],[2,3,4]]\n[[1],4]\n\n[9]\n[[8,7,6]]\n\n[[4,4],4,4]\n[[4,4],4,4,4]\n\n[7,7,7,7]\n[7,7,7]\n\n[]\n[3]\n\n[[[]]]\n[[]]\n\n[1,[2,[3,[4,[5,6,7]]]],8,9]\n[1,[2,[3,[4,[5,6,0]]]],8,9]";
+		let dividers = ["[[6]]", "[[2]]", "[[10]]"].map(|d| d.parse::<Packet>().unwrap());
+		let sorted = sorted_packets(data, &dividers).unwrap();
+		assert_eq!(sorted.len(), 19);
+		assert_eq!(sorted[0].to_string(), "[]");
+		assert_eq!(sorted[17].to_string(), "[9]");
+
+		let packets = day13::parse_packets(data).unwrap();
+		let positions = divider_positions(&packets, &dividers);
+		assert_eq!(positions, vec![14, 10, 19]);
+		for (divider, position) in dividers.iter().zip(positions) {
+			assert_eq!(sorted.iter().position(|p| p == divider), Some(position - 1));
+		}
+		assert_eq!(sorted_packets("[1]\n[1,", &[]).unwrap_err(),
+			"Line 2: Unexpected end of input at position 3");
+	}
+
+	#[test]
+	fn day13_nesting_limit() {
+		use day13::{Packet, MAX_DEPTH};
+		let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+		let deepest = nested(MAX_DEPTH).parse::<Packet>().unwrap();
+		assert!(deepest > nested(MAX_DEPTH - 1).parse().unwrap());
+		let trace = deepest.compare_with_trace(&deepest);
+		assert!(trace.ordering().is_eq());
+		assert_eq!(trace.steps().len(), MAX_DEPTH);
+		assert_eq!(Packet::try_from(serde_json::Value::from(&deepest)).unwrap(), deepest);
+
+		// Far too deep to recurse through, but rejected before it matters
+		let err = nested(200_000).parse::<Packet>().unwrap_err();
+		assert_eq!(err.position(), MAX_DEPTH);
+		assert_eq!(err.to_string(), "Lists nest deeper than 128 levels at position 128");
+
+		let value = (0..=MAX_DEPTH).fold(serde_json::json!([]),
+			|value, _| serde_json::Value::Array(vec![value]));
+		assert!(Packet::try_from(value).is_err());
+	}
 }
@@ -13,7 +13,10 @@
 #![deny(missing_docs)]
 #![deny(rustdoc::missing_crate_level_docs)]
 
-use std::collections::{HashMap, HashSet};
+use std::{
+	collections::HashSet,
+	ops::RangeInclusive,
+};
 
 /// Solve Advent of Code day 14 part one
 ///
@@ -31,149 +34,542 @@ use std::collections::{HashMap, HashSet};
 /// If any conversion assumed to be valid with the input fails, panics.
 #[must_use]
 pub fn solve_part_one(data: &str) -> usize {
-	let mut states: HashMap<(isize, isize), SandState> = HashMap::new();
-	// Trace the paths
-	let blocks = trace_paths(data);
-	let mut num_sand = 0;
-	let floor_y = blocks.iter().map(|(y, _)| *y).max().expect("Floor Y");
-	loop {
-		//print_grid(&blocks, &states);
-		// Drop the sand
-		// Will return true if sand dropped below floor level
-		if drop_sand(floor_y, &blocks, &mut states).1 {
-			return num_sand;
+	let mut cave = Cave::abyss(data).expect("Valid input");
+	cave.run_until_blocked()
+}
+
+fn trace_paths(data: &str) -> Result<HashSet<(isize, isize)>, String> {
+	let mut rocks = HashSet::new();
+	// Split the thing into paths
+	for (idx, path) in data.lines().enumerate() {
+		let nodes = path.trim().split(" -> ")
+			.map(|end| {
+				let (x, y) = end.split_once(',')
+					.ok_or_else(|| format!("Line {}: invalid point '{end}'", idx + 1))?;
+				let parse = |v: &str| v.parse::<isize>()
+					.map_err(|e| format!("Line {}: {e}", idx + 1));
+				Ok((parse(x)?, parse(y)?))
+			})
+			.collect::<Result<Vec<(isize, isize)>, String>>()?;
+		if let [single] = nodes[..] {
+			rocks.insert(single);
+		}
+		for c in nodes.windows(2) {
+			let (x_1, y_1) = c[0];
+			let (x_2, y_2) = c[1];
+			if x_1 != x_2 && y_1 != y_2 {
+				return Err(format!("Line {}: diagonal rock path", idx + 1));
+			}
+			for x in x_1.min(x_2)..=x_1.max(x_2) {
+				for y in y_1.min(y_2)..=y_1.max(y_2) {
+					rocks.insert((x, y));
+				}
+			}
 		}
-		num_sand += 1;
 	}
+	if rocks.is_empty() {
+		return Err(String::from("No rock path"));
+	}
+	Ok(rocks)
+}
+
+/// What can be found in a cell of the cave
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Tile {
+	/// Nothing, sand can go through
+	Air,
+	/// Rock, from a path of the scan or the floor
+	Rock,
+	/// Sand that came to rest
+	Sand,
+}
+
+/// What happened to a grain of sand dropped in the [`Cave`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Grain {
+	/// The grain came to rest at `(x, y)`
+	Settled((isize, isize)),
+	/// The grain fell below all of the rocks, into the abyss
+	Abyss,
+	/// Sand came to rest on every source, no grain can come out anymore
+	Blocked,
+}
+
+/// What lies below the rocks of the cave
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FloorMode {
+	/// Nothing, sand falls forever, as in part one
+	Abyss,
+	/// An infinite floor, this many units below the lowest rock, as in part
+	/// two with an offset of `2`
+	Floor(isize),
+	/// A floor, this many units below the lowest rock, between two walls
+	Walls {
+		/// The `x` of the left wall
+		left: isize,
+		/// The `x` of the right wall
+		right: isize,
+		/// How far below the lowest rock the floor is
+		offset: isize,
+	},
 }
 
-fn drop_sand(floor: isize,
-			 grid: &HashSet<(isize, isize)>,
-			 states: &mut HashMap<(isize, isize), SandState>
-) -> ((isize, isize), bool) {
-	// Now, try and drop one unit
-	let mut cods = (0, 500);
-	while cods.0 < floor {
-		// Look at the current state map
-		// Depending on the state, take actions
-		match states.get(&cods).unwrap_or(&SandState::GoDown) {
-			SandState::GoDown => {
-				let down = (cods.0+1, cods.1);
-				if grid.contains(&down) || *states.get(&down)
-					.unwrap_or(&SandState::GoDown) == SandState::Still {
-						// Uhm, let's not go down
-						// Change the current state
-						states.insert(cods, SandState::GoLeft);
-					} else {
-						// Move
-						states.insert(cods, SandState::GoDown);
-						cods = down;
-					}
-			},
-			SandState::GoLeft => {
-				let left = (cods.0+1, cods.1-1);
-				if grid.contains(&left) || *states.get(&left)
-					.unwrap_or(&SandState::GoDown) == SandState::Still {
-						states.insert(cods, SandState::GoRight);
-					} else {
-						cods = left;
-					}
-			},
-			SandState::GoRight => {
-				let right = (cods.0+1, cods.1+1);
-				if grid.contains(&right) || *states.get(&right)
-					.unwrap_or(&SandState::GoDown) == SandState::Still {
-						states.insert(cods, SandState::GoStill);
-					} else {
-						cods = right;
-					}
-			},
-			SandState::GoStill => {
-				states.insert(cods, SandState::Still);
-				return (cods, false);
-			},
-			SandState::Still => unreachable!()
+impl FloorMode {
+	const fn offset(self) -> Option<isize> {
+		match self {
+			Self::Abyss => None,
+			Self::Floor(offset) | Self::Walls { offset, .. } => Some(offset),
 		}
 	}
-	(cods, true)
 }
 
-fn trace_paths(data: &str) -> HashSet<(isize, isize)> {
-	// Split the thing into paths
-	data.trim().split('\n')
-		.flat_map(|path| {
-			let nodes = path.split(" -> ")
-				.map(|end| {
-					let nums = end.split(',')
-						.map(|x| x.parse::<isize>().unwrap())
-						.collect::<Vec<isize>>();
-					// The problem uses x,y coordinates
-					(nums[1], nums[0])
-				})
-				.collect::<Vec<(isize, isize)>>();
-			nodes[..].windows(2)
-				.flat_map(|c| {
-					let (y_1, x_1) = c[0];
-					let (y_2, x_2) = c[1];
-
-					if y_2 == y_1 {
-						let x_min = x_1.min(x_2);
-						let x_max = x_2.max(x_1);
-						(x_min..=x_max)
-							.map(|v| (y_1, v))
-							.collect::<Vec<(isize, isize)>>()
-					} else {
-						let y_min = y_1.min(y_2);
-						let y_max = y_1.max(y_2);
-						(y_min..=y_max)
-							.map(|v| (v, x_1))
-							.collect()
-					}
-				})
-				.collect::<Vec<(isize, isize)>>()
-		})
-		.collect::<HashSet<(isize, isize)>>()
+/// The settings of a [`Cave`] : where sand comes from, and what lies below
+/// the rocks
+///
+/// By default, sand comes from `(500, 0)` and falls in the abyss, like in
+/// part one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaveConfig {
+	sources: Vec<(isize, isize)>,
+	floor: FloorMode,
+}
+
+impl Default for CaveConfig {
+	fn default() -> Self {
+		Self { sources: vec![(500, 0)], floor: FloorMode::Abyss }
+	}
 }
 
-/*fn print_grid(grid: &HashSet<(isize, isize)>, states: &HashMap<(isize, isize), SandState>) {
-	let mut whyes = grid.iter().map(|(y, _)| *y).collect::<Vec<isize>>();
-	let mut ekkses = grid.iter().map(|(_, x)| *x).collect::<Vec<isize>>();
-	whyes.sort_unstable();
-	ekkses.sort_unstable();
-	let y_min: isize = *whyes.first().expect("Y minimum").min(&0);
-	let y_max: isize = *whyes.last().expect("Y maximum").max(&0);
-	let x_min: isize = *ekkses.first().expect("X minimum").min(&500);
-	let x_max: isize = *ekkses.last().expect("X maximum").max(&500);
-
-	for y in y_min..=y_max {
-		for x in x_min..=x_max {
-			if y == 0 && x == 500 {
-				print!("X");
-			} else if grid.contains(&(y, x)) {
-				print!("#");
-			} else if let Some(s) = states.get(&(y, x)) {
-				print!("{}", match s {
-					SandState::GoDown => "v",
-					SandState::GoLeft => "/",
-					SandState::GoRight => "\\",
-					SandState::GoStill => "!",
-					SandState::Still => "o"
-				});
-			} else {
-				print!("⋅");
+impl CaveConfig {
+	/// Build the settings of part one
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Use a single source of sand at `(x, y)`
+	#[must_use]
+	pub fn source(self, source: (isize, isize)) -> Self {
+		self.sources(&[source])
+	}
+
+	/// Use several sources of sand, which take turns dropping grains
+	#[must_use]
+	pub fn sources(mut self, sources: &[(isize, isize)]) -> Self {
+		self.sources = sources.to_vec();
+		self
+	}
+
+	/// Set what lies below the rocks
+	#[must_use]
+	pub const fn floor(mut self, floor: FloorMode) -> Self {
+		self.floor = floor;
+		self
+	}
+}
+
+/// A slice of the cave, with its rocks and the sand that came to rest
+///
+/// The cave is stored as a dense grid that spans every position sand can
+/// reach. The path followed by the last grain of each source is kept as a
+/// stack : the next grain follows the same path until the cell right above
+/// where the last one came to rest, so it resumes its fall from there instead
+/// of from the source.
+///
+/// Positions are given as `(x, y)`, with `y` growing downwards.
+#[derive(Debug, Clone)]
+pub struct Cave {
+	grid: Vec<Tile>,
+	min_x: isize,
+	min_y: isize,
+	width: usize,
+	height: usize,
+	floor: FloorMode,
+	sources: Vec<(usize, usize)>,
+	paths: Vec<Vec<(usize, usize)>>,
+	next_source: usize,
+	settled: usize,
+	abyss_from: Option<(isize, isize)>,
+}
+
+impl Cave {
+	/// Build a cave whose bottom opens on the abyss, as in part one
+	///
+	/// # Arguments
+	///
+	///  - `data` : the scan of the rock paths, one path per line
+	///
+	/// # Errors
+	///
+	/// Returns a `String` describing the problem if the scan is invalid.
+	pub fn abyss(data: &str) -> Result<Self, String> {
+		Self::new(data, &CaveConfig::new())
+	}
+
+	/// Build a cave with an infinite floor two units below the lowest rock, as
+	/// in part two
+	///
+	/// # Arguments
+	///
+	///  - `data` : the scan of the rock paths, one path per line
+	///
+	/// # Errors
+	///
+	/// Returns a `String` describing the problem if the scan is invalid.
+	pub fn floored(data: &str) -> Result<Self, String> {
+		Self::new(data, &CaveConfig::new().floor(FloorMode::Floor(2)))
+	}
+
+	/// Build a cave
+	///
+	/// # Arguments
+	///
+	///  - `data` : the scan of the rock paths, one path per line
+	///  - `config` : the [`CaveConfig`] of the cave
+	///
+	/// # Errors
+	///
+	/// Returns a `String` describing the problem if the scan is invalid, if
+	/// there is no source, if the floor is not below the rocks, or if a
+	/// source is not above the floor and between the walls.
+	pub fn new(data: &str, config: &CaveConfig) -> Result<Self, String> {
+		let mut rocks = trace_paths(data)?;
+		if config.sources.is_empty() {
+			return Err(String::from("No source of sand"));
+		}
+		let first = config.sources[0];
+		let ys = || rocks.iter().chain(&config.sources).map(|p| p.1);
+		let xs = || rocks.iter().chain(&config.sources).map(|p| p.0);
+		let min_y = ys().fold(first.1, isize::min);
+		let max_rock_y = rocks.iter().map(|r| r.1).fold(isize::MIN, isize::max);
+		let mut min_x = xs().fold(first.0, isize::min);
+		let mut max_x = xs().fold(first.0, isize::max);
+
+		// The last row of the grid, the floor itself is not stored
+		let max_y = match config.floor.offset() {
+			None => ys().fold(first.1, isize::max),
+			Some(offset) if offset < 1 =>
+				return Err(String::from("The floor must be below the rocks")),
+			Some(offset) => {
+				let floor_y = max_rock_y + offset;
+				if config.sources.iter().any(|s| s.1 >= floor_y) {
+					return Err(String::from("Source below the floor"));
+				}
+				// Sand spreads at most one column per row on each side
+				for source in &config.sources {
+					min_x = min_x.min(source.0 - (floor_y - source.1));
+					max_x = max_x.max(source.0 + (floor_y - source.1));
+				}
+				floor_y - 1
+			}
+		};
+
+		if let FloorMode::Walls { left, right, .. } = config.floor {
+			if config.sources.iter().any(|s| s.0 <= left || s.0 >= right) {
+				return Err(String::from("Source outside of the walls"));
+			}
+			rocks.retain(|r| left < r.0 && r.0 < right);
+			for y in min_y..=max_y {
+				rocks.insert((left, y));
+				rocks.insert((right, y));
+			}
+			min_x = left;
+			max_x = right;
+		} else {
+			// One more column on each side, for grains sliding into the abyss
+			min_x -= 1;
+			max_x += 1;
+		}
+
+		let width = max_x.abs_diff(min_x) + 1;
+		let height = max_y.abs_diff(min_y) + 1;
+		let mut cave = Self {
+			grid: vec![Tile::Air; width * height],
+			min_x,
+			min_y,
+			width,
+			height,
+			floor: config.floor,
+			sources: Vec::new(),
+			paths: vec![Vec::new(); config.sources.len()],
+			next_source: 0,
+			settled: 0,
+			abyss_from: None,
+		};
+		for &rock in &rocks {
+			// Rocks under the floor don't matter
+			if let Some((col, row)) = cave.index(rock) {
+				cave.grid[row * width + col] = Tile::Rock;
 			}
 		}
-		println!();
+		cave.sources = config.sources.iter()
+			.map(|&s| cave.index(s).ok_or_else(|| format!("Source {s:?} out of the cave")))
+			.collect::<Result<Vec<(usize, usize)>, String>>()?;
+		Ok(cave)
 	}
-}*/
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-enum SandState {
-	Still,
-	GoDown,
-	GoLeft,
-	GoRight,
-	GoStill,
+	/// Grid coordinates of a position, if it is stored in the grid
+	fn index(&self, (x, y): (isize, isize)) -> Option<(usize, usize)> {
+		let col = usize::try_from(x - self.min_x).ok()?;
+		let row = usize::try_from(y - self.min_y).ok()?;
+		(col < self.width && row < self.height).then_some((col, row))
+	}
+
+	fn position(&self, (col, row): (usize, usize)) -> (isize, isize) {
+		(self.min_x + isize::try_from(col).expect("Reasonable width"),
+		 self.min_y + isize::try_from(row).expect("Reasonable height"))
+	}
+
+	/// What is at `(x, y)`
+	#[must_use]
+	pub fn tile(&self, point: (isize, isize)) -> Tile {
+		let below = usize::try_from(point.1 - self.min_y).ok() == Some(self.height);
+		match (self.index(point), self.floor) {
+			(Some((col, row)), _) => self.grid[row * self.width + col],
+			(None, FloorMode::Floor(_)) if below => Tile::Rock,
+			(None, FloorMode::Walls { left, right, .. })
+				if below && (left..=right).contains(&point.0) => Tile::Rock,
+			(None, _) => Tile::Air,
+		}
+	}
+
+	/// How many grains of sand came to rest
+	#[must_use]
+	pub const fn settled(&self) -> usize {
+		self.settled
+	}
+
+	/// Drop a single grain of sand
+	///
+	/// Sources take turns, skipping those that are blocked.
+	///
+	/// # Return value
+	///
+	/// Returns what became of the [`Grain`].
+	pub fn step(&mut self) -> Grain {
+		for _ in 0..self.sources.len() {
+			let source = self.next_source;
+			self.next_source = (source + 1) % self.sources.len();
+			match self.drop_from(source) {
+				Grain::Blocked => {},
+				grain => return grain,
+			}
+		}
+		Grain::Blocked
+	}
+
+	fn drop_from(&mut self, source: usize) -> Grain {
+		let abyss = self.floor == FloorMode::Abyss;
+		let path = &mut self.paths[source];
+		// Grains from other sources may have filled the end of the path
+		while path.last()
+			.is_some_and(|&(c, r)| self.grid[r * self.width + c] != Tile::Air) {
+			path.pop();
+		}
+		// Resume from the last cell before where the previous grain stopped
+		let start = self.sources[source];
+		let mut current = match path.pop() {
+			Some(cell) => cell,
+			None if self.grid[start.1 * self.width + start.0] != Tile::Air
+				=> return Grain::Blocked,
+			None => start,
+		};
+
+		loop {
+			let (col, row) = current;
+			// Falling out of the grid is falling in the abyss, or on the floor
+			if row + 1 == self.height {
+				if !abyss {
+					break;
+				}
+				path.push(current);
+				self.abyss_from = Some(self.position((col, row + 1)));
+				return Grain::Abyss;
+			}
+			let next = [col, col - 1, col + 1].into_iter()
+				.find(|&c| self.grid[(row + 1) * self.width + c] == Tile::Air);
+			match next {
+				// The margin columns are always empty, sand can't stop there
+				Some(c) if abyss && (c == 0 || c == self.width - 1) => {
+					path.push(current);
+					self.abyss_from = Some(self.position((c, row + 1)));
+					return Grain::Abyss;
+				},
+				Some(c) => {
+					path.push(current);
+					current = (c, row + 1);
+				},
+				None => break,
+			}
+		}
+
+		let (col, row) = current;
+		self.grid[row * self.width + col] = Tile::Sand;
+		self.settled += 1;
+		Grain::Settled(self.position((col, row)))
+	}
+
+	/// Drop grains of sand until one falls in the abyss or every source is
+	/// blocked
+	///
+	/// # Return value
+	///
+	/// Returns the number of grains that came to rest in total.
+	pub fn run_until_blocked(&mut self) -> usize {
+		while let Grain::Settled(_) = self.step() {}
+		self.settled
+	}
+
+	/// Count the grains of sand that come to rest before every source is
+	/// blocked, without dropping them one by one
+	///
+	/// When there is a floor, sand ends up filling every cell it can reach : a
+	/// cell gets sand if it is a source or one of the three cells above it has
+	/// sand, and it is not rock. Filling the cave row by row gives the answer
+	/// in a single pass. This does not change the cave.
+	///
+	/// # Return value
+	///
+	/// Returns the number of grains, or `None` if sand falls in the abyss.
+	#[must_use]
+	pub fn triangle_fill(&self) -> Option<usize> {
+		if self.floor == FloorMode::Abyss {
+			return None;
+		}
+		let mut row_sand = vec![false; self.width];
+		let mut count = 0;
+		for row in 0..self.height {
+			let mut next = vec![false; self.width];
+			for col in 1..self.width - 1 {
+				let fed = self.sources.contains(&(col, row))
+					|| (row > 0 && (row_sand[col - 1] || row_sand[col] || row_sand[col + 1]));
+				next[col] = fed && self.grid[row * self.width + col] != Tile::Rock;
+			}
+			count += next.iter().filter(|&&b| b).count();
+			row_sand = next;
+		}
+		Some(count)
+	}
+
+	/// The area shown when drawing the cave without cropping : the whole grid,
+	/// and the floor if there is one
+	fn default_view(&self) -> (RangeInclusive<isize>, RangeInclusive<isize>) {
+		let (max_x, max_y) = self.position((self.width - 1, self.height - 1));
+		let max_y = if self.floor == FloorMode::Abyss { max_y } else { max_y + 1 };
+		(self.min_x..=max_x, self.min_y..=max_y)
+	}
+
+	/// The characters of the drawing, row by row
+	fn view(&self, crop: Option<(RangeInclusive<isize>, RangeInclusive<isize>)>)
+		-> Vec<Vec<char>> {
+		let (xs, ys) = crop.unwrap_or_else(|| self.default_view());
+		let falling = self.paths.iter()
+			.flatten()
+			.map(|&cell| self.position(cell))
+			.collect::<HashSet<(isize, isize)>>();
+		let sources = self.sources.iter()
+			.map(|&cell| self.position(cell))
+			.collect::<HashSet<(isize, isize)>>();
+
+		ys.map(|y| xs.clone().map(|x| match self.tile((x, y)) {
+			Tile::Rock => '#',
+			Tile::Sand => 'o',
+			Tile::Air if sources.contains(&(x, y)) => '+',
+			Tile::Air if falling.contains(&(x, y))
+				|| self.abyss_from.is_some_and(|a| a.0 == x && a.1 <= y) => '~',
+			Tile::Air => '.',
+		}).collect()).collect()
+	}
+
+	/// Draw the cave in the puzzle's notation
+	///
+	/// Rock is drawn as `#`, sand at rest as `o`, sources as `+` and the path
+	/// of the last grains as `~`. When a grain fell in the abyss, its fall is
+	/// drawn down to the bottom of the drawing.
+	///
+	/// # Arguments
+	///
+	///  - `crop` : if set, the ranges of `x` and `y` to draw, otherwise the
+	///    whole cave is drawn
+	///
+	/// # Return value
+	///
+	/// Returns a `String` with one line per row.
+	#[must_use]
+	pub fn render(&self, crop: Option<(RangeInclusive<isize>, RangeInclusive<isize>)>)
+		-> String {
+		self.view(crop).into_iter()
+			.map(|row| row.into_iter().collect::<String>())
+			.collect::<Vec<String>>()
+			.join("\n")
+	}
+
+	/// Draw the cave as a binary PGM image, one pixel per cell
+	///
+	/// Air is black, the path of the last grains dark grey, rock grey, sand
+	/// light grey and sources white.
+	///
+	/// # Arguments
+	///
+	///  - `crop` : if set, the ranges of `x` and `y` to draw, otherwise the
+	///    whole cave is drawn
+	///
+	/// # Return value
+	///
+	/// Returns the bytes of the image.
+	#[must_use]
+	pub fn to_pgm(&self, crop: Option<(RangeInclusive<isize>, RangeInclusive<isize>)>)
+		-> Vec<u8> {
+		self.image(crop, "P5", |c| vec![match c {
+			'~' => 0x40,
+			'#' => 0x80,
+			'o' => 0xc0,
+			'+' => 0xff,
+			_ => 0x00,
+		}])
+	}
+
+	/// Draw the cave as a binary PPM image, one pixel per cell
+	///
+	/// Air is black, the path of the last grains blue, rock grey, sand yellow
+	/// and sources red.
+	///
+	/// # Arguments
+	///
+	///  - `crop` : if set, the ranges of `x` and `y` to draw, otherwise the
+	///    whole cave is drawn
+	///
+	/// # Return value
+	///
+	/// Returns the bytes of the image.
+	#[must_use]
+	pub fn to_ppm(&self, crop: Option<(RangeInclusive<isize>, RangeInclusive<isize>)>)
+		-> Vec<u8> {
+		self.image(crop, "P6", |c| match c {
+			'~' => vec![0x40, 0x60, 0xff],
+			'#' => vec![0x80, 0x80, 0x80],
+			'o' => vec![0xf0, 0xd0, 0x60],
+			'+' => vec![0xff, 0x00, 0x00],
+			_ => vec![0x00, 0x00, 0x00],
+		})
+	}
+
+	fn image<F>(&self, crop: Option<(RangeInclusive<isize>, RangeInclusive<isize>)>,
+				magic: &str, colour: F) -> Vec<u8>
+		where F: Fn(char) -> Vec<u8> {
+		let view = self.view(crop);
+		let width = view.first().map_or(0, Vec::len);
+		let mut image = format!("{magic}\n{width} {}\n255\n", view.len())
+			.into_bytes();
+		image.extend(view.into_iter().flatten().flat_map(colour));
+		image
+	}
+}
+
+impl std::fmt::Display for Cave {
+	fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(fmt, "{}", self.render(None))
+	}
 }
 
 /// Solve Advent of Code day 14 part two
@@ -192,27 +588,8 @@ enum SandState {
 /// If any conversion assumed to be valid with the input fails, panics.
 #[must_use]
 pub fn solve_part_two(data: &str) -> usize {
-	let mut states: HashMap<(isize, isize), SandState> = HashMap::new();
-	// Trace the paths
-	let mut blocks = trace_paths(data);
-	let mut num_sand = 1;
-	let floor_y = blocks.iter().map(|(y, _)| *y).max().expect("Floor Y");
-	let mut ekkses = blocks.iter().map(|(_, x)| *x).collect::<Vec<isize>>();
-	ekkses.sort_unstable();
-	// Add the bottom
-	for x in 500-(floor_y+3)..=(500+floor_y+3) {
-		blocks.insert((floor_y+2, x));
-	}
-	loop {
-		// Drop the sand
-		// Will return true if sand dropped below floor level
-		let cods = drop_sand(floor_y+2, &blocks, &mut states).0;
-		if cods == (0, 500) {
-			//print_grid(&blocks, &states);
-			return num_sand;
-		}
-		num_sand += 1;
-	}
+	let cave = Cave::floored(data).expect("Valid input");
+	cave.triangle_fill().expect("Floored cave")
 }
 
 // vim: set tw=80:
@@ -3,8 +3,7 @@
 
 extern crate common;
 use common::read_data;
-mod lib;
-use lib::{solve_part_one, solve_part_two};
+use day14::{solve_part_one, solve_part_two};
 
 #[doc(hidden)]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -22,4 +21,92 @@ mod test {
 
 	test!(day14_01_example1, 1, 24, "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9");
 	test!(day14_02_example1, 2, 93, "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9");
+
+	use day14::{Cave, CaveConfig, FloorMode, Grain};
+
+	#[test]
+	fn day14_cave_steps() {
+		let data = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9";
+		let mut cave = Cave::abyss(data).unwrap();
+		assert_eq!(Grain::Settled((500, 8)), cave.step());
+		assert_eq!(Grain::Settled((499, 8)), cave.step());
+		assert_eq!(24, cave.run_until_blocked());
+		assert_eq!(Grain::Abyss, cave.step());
+
+		let mut cave = Cave::floored(data).unwrap();
+		assert_eq!(Some(93), cave.triangle_fill());
+		assert_eq!(93, cave.run_until_blocked());
+		assert_eq!(Grain::Blocked, cave.step());
+
+		assert!(Cave::abyss("498,4 -> 497,5").is_err());
+	}
+
+	#[test]
+	fn day14_cave_config() {
+		let data = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9";
+		// Same as part two
+		let config = CaveConfig::new().floor(FloorMode::Floor(2));
+		assert_eq!(93, Cave::new(data, &config).unwrap().run_until_blocked());
+
+		// Walls hold the sand that would otherwise spread on the floor
+		let config = CaveConfig::new()
+			.floor(FloorMode::Walls { left: 497, right: 503, offset: 1 });
+		let mut cave = Cave::new(data, &config).unwrap();
+		let fill = cave.triangle_fill();
+		assert_eq!(fill, Some(cave.run_until_blocked()));
+
+		// Two sources, and what one fills is not filled twice
+		let config = CaveConfig::new()
+			.sources(&[(500, 0), (480, 3)])
+			.floor(FloorMode::Floor(2));
+		let mut cave = Cave::new(data, &config).unwrap();
+		let fill = cave.triangle_fill();
+		assert_eq!(fill, Some(cave.run_until_blocked()));
+		assert_eq!(Grain::Blocked, cave.step());
+
+		assert!(Cave::new(data, &CaveConfig::new().floor(FloorMode::Floor(0))).is_err());
+		assert!(Cave::new(data, &CaveConfig::new().source((500, 11))
+			.floor(FloorMode::Floor(2))).is_err());
+		assert!(Cave::new(data, &CaveConfig::new().sources(&[])).is_err());
+	}
+
+	#[test]
+	fn day14_render() {
+		let data = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9";
+		let mut cave = Cave::abyss(data).unwrap();
+		for _ in 0..22 {
+			cave.step();
+		}
+		assert_eq!(concat!(
+			"......+...\n",
+			"......~...\n",
+			"......o...\n",
+			".....ooo..\n",
+			"....#ooo##\n",
+			"....#ooo#.\n",
+			"..###ooo#.\n",
+			"....oooo#.\n",
+			"...ooooo#.\n",
+			"#########."), cave.render(Some((494..=503, 0..=9))));
+
+		cave.run_until_blocked();
+		assert_eq!(concat!(
+			".......+...\n",
+			".......~...\n",
+			"......~o...\n",
+			".....~ooo..\n",
+			"....~#ooo##\n",
+			"...~o#ooo#.\n",
+			"..~###ooo#.\n",
+			"..~..oooo#.\n",
+			".~o.ooooo#.\n",
+			"~#########.\n",
+			"~..........\n",
+			"~..........\n",
+			"~.........."), cave.render(Some((493..=503, 0..=12))));
+
+		let image = cave.to_ppm(None);
+		assert!(image.starts_with(b"P6\n12 10\n255\n"));
+		assert_eq!(b"P6\n12 10\n255\n".len() + 12 * 10 * 3, image.len());
+	}
 }
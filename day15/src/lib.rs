@@ -13,14 +13,15 @@
 #![deny(missing_docs)]
 #![deny(rustdoc::missing_crate_level_docs)]
 
-use std::collections::HashSet;
+use std::{
+	collections::HashSet,
+	ops::RangeInclusive,
+	sync::LazyLock,
+};
 
-use lazy_static::lazy_static;
 use regex::Regex;
 
-lazy_static! {
-	static ref LINERGX: Regex = Regex::new(r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$").unwrap();
-}
+static LINERGX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$").unwrap());
 
 /// Solve Advent of Code day 15 part one
 ///
@@ -38,60 +39,515 @@ lazy_static! {
 /// If any conversion assumed to be valid with the input fails, panics.
 #[must_use]
 pub fn solve_part_one(data: &str) -> usize {
-	let sensors = data.trim().split('\n')
-		.map(|line| {
-			let caps = LINERGX.captures(line).expect("Regex works");
-			let sens_x = caps[1].parse::<isize>().expect("sensor x");
-			let sens_y = caps[2].parse::<isize>().expect("sensor y");
-			let baco_x = caps[3].parse::<isize>().expect("beacon x");
-			let baco_y = caps[4].parse::<isize>().expect("beacon y");
-			Sensor {
-				x: sens_x, y: sens_y,
-				radius: distance((sens_y, sens_x), (baco_y, baco_x))
-			}
-		})
-		.collect::<Vec<Sensor>>();
-	let bacons = data.trim().split('\n')
-		.map(|line| {
-			let caps = LINERGX.captures(line).expect("Regex works");
-			let baco_x = caps[3].parse::<isize>().expect("beacon x");
-			let baco_y = caps[4].parse::<isize>().expect("beacon y");
-			(baco_y, baco_x)
-		})
-		.collect::<HashSet<(isize, isize)>>();
-	let minx = sensors.iter()
-		.map(|s| s.x - s.radius)
-		.min().expect("More than 0 sensors");
-	let maxx = sensors.iter()
-		.map(|s| s.x + s.radius)
-		.max().expect("More than 0 sensors");
-
-	let mut count = 0;
-	let y = 2_000_000;
-	for x in minx..=maxx {
-		let cods = (y, x);
-		// For every single one of these...
-		if sensors.iter()
-			.any(|s| distance(cods, (s.y, s.x)) <= s.radius) &&
-			!bacons.contains(&cods) {
-			count += 1;
-		}
-	}
+	solve_part_one_at(data, 2_000_000)
+}
+
+/// Count the positions of a row where a beacon cannot be present
+///
+/// # Arguments
+///
+///  - `data` : a `&str` that holds the input for today's puzzle
+///  - `row` : the `y` coordinate of the row to scan
+///
+/// # Return value
+///
+/// This function returns a `usize`, the number of positions of the row that
+/// are covered by a sensor and hold no beacon.
+///
+/// # Panics
+///
+/// If any conversion assumed to be valid with the input fails, panics.
+#[must_use]
+pub fn solve_part_one_at(data: &str, row: isize) -> usize {
+	let field = data.parse::<SensorField>().expect("Valid input");
+	field.covered_in_row(row)
+}
 
-	count
+/// Find the positions of a search square where the distress beacon could be
+///
+/// # Arguments
+///
+///  - `data` : a `&str` that holds the input for today's puzzle
+///  - `bounds` : the range of both `x` and `y` in the search square
+///
+/// # Return value
+///
+/// This function returns every position `(x, y)` of the square that is not
+/// covered by any sensor, sorted. See [`SensorField::uncovered_points`].
+///
+/// # Panics
+///
+/// If any conversion assumed to be valid with the input fails, panics.
+#[must_use]
+pub fn find_beacon_in(data: &str, bounds: &RangeInclusive<isize>)
+	-> Vec<(isize, isize)> {
+	let field = data.parse::<SensorField>().expect("Valid input");
+	field.uncovered_points(bounds)
 }
 
-#[derive(Debug)]
-struct Sensor {
-	y: isize,
+/// A sensor, and the closest beacon it detected
+///
+/// Since the sensor only ever detects its closest beacon, no other beacon can
+/// be within the Manhattan distance between the two : that distance is the
+/// radius of the diamond covered by the sensor.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Sensor {
 	x: isize,
+	y: isize,
+	beacon: (isize, isize),
 	radius: isize
 }
 
+impl Sensor {
+	/// Build a sensor from its position and the position of its beacon, both
+	/// as `(x, y)`
+	#[must_use]
+	pub const fn new(position: (isize, isize), beacon: (isize, isize)) -> Self {
+		Self {
+			x: position.0, y: position.1,
+			beacon,
+			radius: distance(position, beacon)
+		}
+	}
+
+	/// The position of the sensor, as `(x, y)`
+	#[must_use]
+	pub const fn position(&self) -> (isize, isize) {
+		(self.x, self.y)
+	}
+
+	/// The position of the closest beacon, as `(x, y)`
+	#[must_use]
+	pub const fn beacon(&self) -> (isize, isize) {
+		self.beacon
+	}
+
+	/// The radius of the area covered by the sensor
+	#[must_use]
+	pub const fn radius(&self) -> isize {
+		self.radius
+	}
+
+	/// Whether the point `(x, y)` is in the area covered by the sensor
+	#[must_use]
+	pub const fn covers(&self, point: (isize, isize)) -> bool {
+		distance(point, (self.x, self.y)) <= self.radius
+	}
+
+	/// The part of row `y` covered by the sensor, if any
+	#[must_use]
+	pub fn row_coverage(&self, y: isize) -> Option<RangeInclusive<isize>> {
+		let half_width = self.radius - isize::abs(self.y - y);
+		(half_width >= 0).then(|| (self.x - half_width)..=(self.x + half_width))
+	}
+}
+
 const fn distance(a: (isize, isize), b: (isize, isize)) -> isize {
 	isize::abs(a.0 - b.0) + isize::abs(a.1 - b.1)
 }
 
+/// All of the sensors deployed in the tunnels
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SensorField {
+	sensors: Vec<Sensor>,
+}
+
+impl std::str::FromStr for SensorField {
+	type Err = String;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let sensors = s.lines()
+			.enumerate()
+			.map(|(idx, line)| {
+				let caps = LINERGX.captures(line.trim())
+					.ok_or_else(|| format!("Line {}: unknown format", idx + 1))?;
+				let coords = (1..=4)
+					.map(|i| caps[i].parse::<isize>()
+						.map_err(|e| format!("Line {}: {e}", idx + 1)))
+					.collect::<Result<Vec<isize>, String>>()?;
+				Ok(Sensor::new((coords[0], coords[1]), (coords[2], coords[3])))
+			})
+			.collect::<Result<Vec<Sensor>, String>>()?;
+		Ok(Self::new(sensors))
+	}
+}
+
+impl SensorField {
+	/// Build a field from a list of sensors
+	#[must_use]
+	pub const fn new(sensors: Vec<Sensor>) -> Self {
+		Self { sensors }
+	}
+
+	/// The sensors of the field
+	#[must_use]
+	pub fn sensors(&self) -> &[Sensor] {
+		&self.sensors
+	}
+
+	/// The positions of the beacons detected by the sensors, as `(x, y)`
+	#[must_use]
+	pub fn beacons(&self) -> HashSet<(isize, isize)> {
+		self.sensors.iter().map(Sensor::beacon).collect()
+	}
+
+	/// Whether the point `(x, y)` is covered by at least one sensor
+	#[must_use]
+	pub fn is_covered(&self, point: (isize, isize)) -> bool {
+		self.sensors.iter().any(|s| s.covers(point))
+	}
+
+	/// The parts of row `y` covered by the sensors
+	///
+	/// # Return value
+	///
+	/// Returns the covered ranges of `x`, sorted, with overlapping and
+	/// touching ranges merged together.
+	#[must_use]
+	pub fn row_coverage(&self, y: isize) -> Vec<RangeInclusive<isize>> {
+		let mut ranges = self.sensors.iter()
+			.filter_map(|s| s.row_coverage(y))
+			.collect::<Vec<RangeInclusive<isize>>>();
+		ranges.sort_unstable_by_key(|r| *r.start());
+
+		let mut merged: Vec<RangeInclusive<isize>> = Vec::new();
+		for range in ranges {
+			match merged.last_mut() {
+				// Overlapping or touching, stretch the last one
+				Some(last) if *range.start() <= *last.end() + 1 => {
+					if range.end() > last.end() {
+						*last = *last.start()..=*range.end();
+					}
+				},
+				_ => merged.push(range)
+			}
+		}
+		merged
+	}
+
+	/// How many positions of row `y` cannot contain a beacon
+	///
+	/// Those are the positions covered by a sensor, minus the beacons that are
+	/// actually there.
+	#[must_use]
+	pub fn covered_in_row(&self, y: isize) -> usize {
+		let ranges = self.row_coverage(y);
+		let covered = ranges.iter()
+			.map(|r| r.end().abs_diff(*r.start()) + 1)
+			.sum::<usize>();
+		// Beacons are always covered by their own sensor
+		let beacons = self.beacons().into_iter()
+			.filter(|b| b.1 == y)
+			.count();
+		covered - beacons
+	}
+
+	/// Find the positions of the search square that no sensor covers
+	///
+	/// The gaps left in a row between the covered ranges only appear, vanish
+	/// or change shape on a few rows : where a sensor's area starts, ends or
+	/// is widest, and around the crossings of the lines running right outside
+	/// of the diamonds, with one another or with the edges of the square.
+	/// Between two such rows, either every row has gaps or none has, so only
+	/// the stretches that start with a gap are scanned row by row.
+	///
+	/// # Arguments
+	///
+	///  - `bounds` : the range of both `x` and `y` in the search square
+	///
+	/// # Return value
+	///
+	/// Returns every uncovered position as `(x, y)`, sorted.
+	#[must_use]
+	pub fn uncovered_points(&self, bounds: &RangeInclusive<isize>)
+		-> Vec<(isize, isize)> {
+		// Diamond edges run along lines where either `x + y` or `x - y` is
+		// constant. Collect those right outside of every diamond.
+		let ascending = self.sensors.iter()
+			.flat_map(|s| {
+				let c = s.x + s.y;
+				[c - s.radius - 1, c + s.radius + 1]
+			})
+			.collect::<HashSet<isize>>();
+		let descending = self.sensors.iter()
+			.flat_map(|s| {
+				let c = s.x - s.y;
+				[c - s.radius - 1, c + s.radius + 1]
+			})
+			.collect::<HashSet<isize>>();
+		let edges = [*bounds.start(), *bounds.end()];
+
+		// Rows around which the gaps may change, with a margin for lines
+		// crossing between two rows
+		let mut crossings = Vec::new();
+		for a in &ascending {
+			crossings.extend(descending.iter().map(|d| (a - d).div_euclid(2)));
+			crossings.extend(edges.iter().map(|e| a - e));
+		}
+		for d in &descending {
+			crossings.extend(edges.iter().map(|e| e - d));
+		}
+		let mut events = crossings.into_iter()
+			.flat_map(|y| y - 2..=y + 2)
+			.chain(self.sensors.iter().flat_map(|s|
+				[s.y - s.radius - 1, s.y - s.radius, s.y, s.y + s.radius,
+				 s.y + s.radius + 1]))
+			.chain(edges)
+			.filter(|y| bounds.contains(y))
+			.collect::<Vec<isize>>();
+		events.sort_unstable();
+		events.dedup();
+
+		let mut points = Vec::new();
+		for (idx, &start) in events.iter().enumerate() {
+			let end = events.get(idx + 1).map_or_else(|| *bounds.end(), |next| next - 1);
+			for y in start..=end {
+				let gaps = self.row_gaps(y, bounds);
+				if gaps.is_empty() {
+					break;
+				}
+				points.extend(gaps.into_iter().flat_map(|g| g.map(move |x| (x, y))));
+			}
+		}
+		points.sort_unstable();
+		points
+	}
+
+	/// Find every area of a rectangle that no sensor covers
+	///
+	/// Every row of the rectangle is scanned for the gaps left between the
+	/// covered ranges, and gaps of consecutive rows that touch are joined
+	/// together. The cost grows with the height of the rectangle, not its
+	/// area.
+	///
+	/// # Arguments
+	///
+	///  - `xs` : the range of `x` in the rectangle
+	///  - `ys` : the range of `y` in the rectangle
+	///
+	/// # Return value
+	///
+	/// Returns the maximal uncovered areas, connected horizontally and
+	/// vertically, sorted by their first row.
+	#[must_use]
+	pub fn uncovered_regions(&self, xs: &RangeInclusive<isize>,
+							 ys: &RangeInclusive<isize>) -> Vec<UncoveredRegion> {
+		// Every gap gets a region, regions are merged with a union-find
+		let mut parents: Vec<usize> = Vec::new();
+		let mut spans: Vec<(isize, RangeInclusive<isize>)> = Vec::new();
+		let mut previous: Vec<(RangeInclusive<isize>, usize)> = Vec::new();
+
+		for y in ys.clone() {
+			let gaps = self.row_gaps(y, xs);
+			let mut current = Vec::with_capacity(gaps.len());
+			let mut prev_idx = 0;
+			for gap in gaps {
+				let id = parents.len();
+				parents.push(id);
+				spans.push((y, gap.clone()));
+				// Both lists are sorted, skip the gaps entirely to the left
+				while prev_idx < previous.len()
+					&& previous[prev_idx].0.end() < gap.start() {
+					prev_idx += 1;
+				}
+				let mut touching = prev_idx;
+				while touching < previous.len()
+					&& previous[touching].0.start() <= gap.end() {
+					union(&mut parents, previous[touching].1, id);
+					touching += 1;
+				}
+				current.push((gap, id));
+			}
+			previous = current;
+		}
+
+		let mut regions: Vec<UncoveredRegion> = Vec::new();
+		let mut region_of: Vec<Option<usize>> = vec![None; spans.len()];
+		for (id, span) in spans.into_iter().enumerate() {
+			let root = find(&mut parents, id);
+			let region = *region_of[root].get_or_insert_with(|| {
+				regions.push(UncoveredRegion { spans: Vec::new() });
+				regions.len() - 1
+			});
+			regions[region].spans.push(span);
+		}
+		regions
+	}
+
+	/// The ranges of row `y` within `xs` that no sensor covers
+	fn row_gaps(&self, y: isize, xs: &RangeInclusive<isize>)
+		-> Vec<RangeInclusive<isize>> {
+		let mut gaps = Vec::new();
+		let mut next = *xs.start();
+		for range in self.row_coverage(y) {
+			if next > *xs.end() { break; }
+			if *range.start() > next {
+				gaps.push(next..=(*range.start() - 1).min(*xs.end()));
+			}
+			next = next.max(*range.end() + 1);
+		}
+		if next <= *xs.end() {
+			gaps.push(next..=*xs.end());
+		}
+		gaps
+	}
+
+	/// What can be seen in the block of `scale` by `scale` positions whose top
+	/// left corner is `(x, y)`, clipped to the rectangle
+	fn block(&self, (x, y): (isize, isize), scale: isize,
+			 xs: &RangeInclusive<isize>, ys: &RangeInclusive<isize>) -> Tile {
+		let x_end = (x + scale - 1).min(*xs.end());
+		let y_end = (y + scale - 1).min(*ys.end());
+		let inside = |p: (isize, isize)| (x..=x_end).contains(&p.0)
+			&& (y..=y_end).contains(&p.1);
+		if self.sensors.iter().any(|s| inside(s.position())) {
+			Tile::Sensor
+		} else if self.sensors.iter().any(|s| inside(s.beacon())) {
+			Tile::Beacon
+		} else if self.is_covered((x.midpoint(x_end), y.midpoint(y_end))) {
+			Tile::Covered
+		} else {
+			Tile::Uncovered
+		}
+	}
+
+	/// The tiles of a rectangle, scaled down, one row after the other
+	fn tiles(&self, xs: &RangeInclusive<isize>, ys: &RangeInclusive<isize>,
+			 scale: usize) -> Vec<Vec<Tile>> {
+		let step = isize::try_from(scale.max(1)).expect("Reasonable scale");
+		ys.clone().step_by(scale.max(1))
+			.map(|y| xs.clone().step_by(scale.max(1))
+				.map(|x| self.block((x, y), step, xs, ys))
+				.collect())
+			.collect()
+	}
+
+	/// Draw the coverage of a rectangle in the puzzle's notation
+	///
+	/// Sensors are drawn as `S`, beacons as `B`, covered positions as `#`
+	/// and uncovered positions as `.`. When scaled down, every character
+	/// stands for a block of `scale` by `scale` positions : it shows a sensor
+	/// or beacon if there is one in the block, and the coverage of the middle
+	/// of the block otherwise.
+	///
+	/// # Arguments
+	///
+	///  - `xs` : the range of `x` to draw
+	///  - `ys` : the range of `y` to draw
+	///  - `scale` : the size of the block behind each character
+	///
+	/// # Return value
+	///
+	/// Returns a `String` with one line per row of blocks.
+	#[must_use]
+	pub fn render(&self, xs: &RangeInclusive<isize>, ys: &RangeInclusive<isize>,
+				  scale: usize) -> String {
+		self.tiles(xs, ys, scale).into_iter()
+			.map(|row| row.into_iter().map(char::from).collect::<String>())
+			.collect::<Vec<String>>()
+			.join("\n")
+	}
+
+	/// Draw the coverage of a rectangle as a binary PGM image
+	///
+	/// Every pixel is a block of `scale` by `scale` positions, as in
+	/// [`SensorField::render`]. Sensors are white, beacons light grey,
+	/// covered positions dark grey and uncovered positions black.
+	///
+	/// # Return value
+	///
+	/// Returns the bytes of the image.
+	#[must_use]
+	pub fn to_pgm(&self, xs: &RangeInclusive<isize>, ys: &RangeInclusive<isize>,
+				  scale: usize) -> Vec<u8> {
+		let tiles = self.tiles(xs, ys, scale);
+		let width = tiles.first().map_or(0, Vec::len);
+		let mut image = format!("P5\n{width} {}\n255\n", tiles.len())
+			.into_bytes();
+		image.extend(tiles.into_iter().flatten().map(|t| match t {
+			Tile::Sensor => 0xff,
+			Tile::Beacon => 0xc0,
+			Tile::Covered => 0x60,
+			Tile::Uncovered => 0x00,
+		}));
+		image
+	}
+}
+
+fn find(parents: &mut [usize], mut id: usize) -> usize {
+	while parents[id] != id {
+		parents[id] = parents[parents[id]];
+		id = parents[id];
+	}
+	id
+}
+
+fn union(parents: &mut [usize], a: usize, b: usize) {
+	let root_a = find(parents, a);
+	let root_b = find(parents, b);
+	parents[root_b] = root_a;
+}
+
+/// A connected area that no sensor covers, as found by
+/// [`SensorField::uncovered_regions`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UncoveredRegion {
+	spans: Vec<(isize, RangeInclusive<isize>)>,
+}
+
+impl UncoveredRegion {
+	/// The rows of the area, as pairs of `y` and the uncovered range of `x`,
+	/// sorted
+	#[must_use]
+	pub fn spans(&self) -> &[(isize, RangeInclusive<isize>)] {
+		&self.spans
+	}
+
+	/// The number of positions in the area
+	#[must_use]
+	pub fn area(&self) -> usize {
+		self.spans.iter()
+			.map(|(_, xs)| xs.end().abs_diff(*xs.start()) + 1)
+			.sum()
+	}
+
+	/// The smallest rectangle holding the area, as ranges of `x` and `y`
+	#[must_use]
+	pub fn bounding_box(&self) -> (RangeInclusive<isize>, RangeInclusive<isize>) {
+		// Spans are sorted by row, and regions always have at least one
+		let (first_y, first_xs) = &self.spans[0];
+		let (last_y, _) = &self.spans[self.spans.len() - 1];
+		let (min_x, max_x) = self.spans.iter()
+			.fold((*first_xs.start(), *first_xs.end()),
+				|(lo, hi), (_, xs)| (lo.min(*xs.start()), hi.max(*xs.end())));
+		(min_x..=max_x, *first_y..=*last_y)
+	}
+
+	/// Whether the point `(x, y)` is in the area
+	#[must_use]
+	pub fn contains(&self, (x, y): (isize, isize)) -> bool {
+		self.spans.iter().any(|(row, xs)| *row == y && xs.contains(&x))
+	}
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Tile {
+	Sensor,
+	Beacon,
+	Covered,
+	Uncovered,
+}
+
+impl From<Tile> for char {
+	fn from(tile: Tile) -> Self {
+		match tile {
+			Tile::Sensor => 'S',
+			Tile::Beacon => 'B',
+			Tile::Covered => '#',
+			Tile::Uncovered => '.',
+		}
+	}
+}
+
 /// Solve Advent of Code day 15 part two
 ///
 /// # Arguments
@@ -108,53 +564,10 @@ const fn distance(a: (isize, isize), b: (isize, isize)) -> isize {
 /// If any conversion assumed to be valid with the input fails, panics.
 #[must_use]
 pub fn solve_part_two(data: &str) -> usize {
-	let sensors = data.trim().split('\n')
-		.map(|line| {
-			let caps = LINERGX.captures(line).expect("Regex works");
-			let sens_x = caps[1].parse::<isize>().expect("sensor x");
-			let sens_y = caps[2].parse::<isize>().expect("sensor y");
-			let baco_x = caps[3].parse::<isize>().expect("beacon x");
-			let baco_y = caps[4].parse::<isize>().expect("beacon y");
-			Sensor {
-				x: sens_x, y: sens_y,
-				radius: distance((sens_y, sens_x), (baco_y, baco_x))
-			}
-		})
-		.collect::<Vec<Sensor>>();
-
-	// Find all of the positions that are just one outside of a sensor field
-	// By our logic, if there exists only one position outside of all fields in
-	// the designated area, it is necessarily one tile away from a field.
-	let edge_cases = sensors.iter()
-		.flat_map(|s| {
-			// Alright, start collecting
-			// Thankfully, Manhatthan distance makes big losange shapes around
-			// the sensors, so it's easy to walk around them
-			let mut poses = Vec::new();
-			for delta in 0..=s.radius {
-				// Start at the left edge, and go up
-				poses.push((s.y - delta, s.x - s.radius - 1 + delta));
-				// Now from up above to the right
-				poses.push((s.y - s.radius - 1 - delta, s.x + delta));
-				// From the right to the bottom
-				poses.push((s.y + delta, s.x + s.radius + 1 - delta));
-				// And now right to left
-				poses.push((s.y + s.radius + 1 + delta, s.x - delta));
-			}
-			poses
-		})
-		.filter(|&(y, x)| (0..=4_000_000).contains(&y) && (0..=4_000_000).contains(&x))
-		//.filter(|&(y, x)| 0 <= y && y <= 20 && 0 <= x && x <= 20)
-		// For each of them, filter out those that are in a zone
-		.filter(|&cods| sensors.iter().all(|s| distance(cods, (s.y, s.x)) > s.radius))
-		.collect::<HashSet<(isize, isize)>>();
-
-	// Now, how many are there?
-
-	assert_eq!(1, edge_cases.len());
-
-	let solution = edge_cases.iter().next().expect("At least one solution");
-	usize::try_from(solution.1 * 4_000_000 + solution.0).expect("Within range")
+	let (x, y) = *find_beacon_in(data, &(0..=4_000_000))
+		.first()
+		.expect("At least one solution");
+	usize::try_from(x * 4_000_000 + y).expect("Within range")
 }
 
 // vim: set tw=80:
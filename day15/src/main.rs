@@ -20,7 +20,61 @@ mod test {
 	use common::test;
 
 	test!(day15_01_example1, 1, 26, "Sensor at x=2, y=2000008: closest beacon is at x=-2, y=2000005\nSensor at x=9, y=2000006: closest beacon is at x=10, y=2000006\nSensor at x=13, y=1999992: closest beacon is at x=15, y=1999993\nSensor at x=12, y=2000004: closest beacon is at x=10, y=2000006\nSensor at x=10, y=2000010: closest beacon is at x=10, y=2000006\nSensor at x=14, y=2000007: closest beacon is at x=10, y=2000006\nSensor at x=8, y=1999997: closest beacon is at x=2, y=2000000\nSensor at x=2, y=1999990: closest beacon is at x=2, y=2000000\nSensor at x=0, y=2000001: closest beacon is at x=2, y=2000000\nSensor at x=20, y=2000004: closest beacon is at x=25, y=2000007\nSensor at x=17, y=2000010: closest beacon is at x=21, y=2000012\nSensor at x=16, y=1999997: closest beacon is at x=15, y=1999993\nSensor at x=14, y=1999993: closest beacon is at x=15, y=1999993\nSensor at x=20, y=1999991: closest beacon is at x=15, y=1999993");
-	// We can't have a test 2 because the parameters vary so wildly
-	// I kept it during development and changed the code after for production
-	//test!(day15_02_example1, 2, 56000011, "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\nSensor at x=9, y=16: closest beacon is at x=10, y=16\nSensor at x=13, y=2: closest beacon is at x=15, y=3\nSensor at x=12, y=14: closest beacon is at x=10, y=16\nSensor at x=10, y=20: closest beacon is at x=10, y=16\nSensor at x=14, y=17: closest beacon is at x=10, y=16\nSensor at x=8, y=7: closest beacon is at x=2, y=10\nSensor at x=2, y=0: closest beacon is at x=2, y=10\nSensor at x=0, y=11: closest beacon is at x=2, y=10\nSensor at x=20, y=14: closest beacon is at x=25, y=17\nSensor at x=17, y=20: closest beacon is at x=21, y=22\nSensor at x=16, y=7: closest beacon is at x=15, y=3\nSensor at x=14, y=3: closest beacon is at x=15, y=3\nSensor at x=20, y=1: closest beacon is at x=15, y=3");
+
+	use day15::{find_beacon_in, solve_part_one_at, SensorField};
+
+	const EXAMPLE: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\nSensor at x=9, y=16: closest beacon is at x=10, y=16\nSensor at x=13, y=2: closest beacon is at x=15, y=3\nSensor at x=12, y=14: closest beacon is at x=10, y=16\nSensor at x=10, y=20: closest beacon is at x=10, y=16\nSensor at x=14, y=17: closest beacon is at x=10, y=16\nSensor at x=8, y=7: closest beacon is at x=2, y=10\nSensor at x=2, y=0: closest beacon is at x=2, y=10\nSensor at x=0, y=11: closest beacon is at x=2, y=10\nSensor at x=20, y=14: closest beacon is at x=25, y=17\nSensor at x=17, y=20: closest beacon is at x=21, y=22\nSensor at x=16, y=7: closest beacon is at x=15, y=3\nSensor at x=14, y=3: closest beacon is at x=15, y=3\nSensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+	#[test]
+	fn day15_sensor_field() {
+		let field = EXAMPLE.parse::<SensorField>().unwrap();
+		assert_eq!(vec![-2..=24], field.row_coverage(10));
+		assert_eq!(26, field.covered_in_row(10));
+		assert_eq!(vec![(14, 11)], field.uncovered_points(&(0..=20)));
+		assert!("Sensor at x=2, y=18".parse::<SensorField>().is_err());
+	}
+
+	#[test]
+	fn day15_example_bounds() {
+		assert_eq!(26, solve_part_one_at(EXAMPLE, 10));
+		assert_eq!(vec![(14, 11)], find_beacon_in(EXAMPLE, &(0..=20)));
+		// A lone sensor leaves the four corners of its square uncovered
+		let lone = "Sensor at x=0, y=0: closest beacon is at x=1, y=0";
+		assert_eq!(vec![(-1, -1), (-1, 1), (1, -1), (1, 1)],
+			find_beacon_in(lone, &(-1..=1)));
+		// In a wider square, every position of the ring is found
+		let ring = find_beacon_in(lone, &(-2..=2));
+		assert_eq!(20, ring.len());
+		assert!(ring.contains(&(-2, 0)) && ring.contains(&(-1, -1)));
+		assert!(!ring.contains(&(0, 1)));
+	}
+
+	#[test]
+	fn day15_uncovered_regions() {
+		let field = EXAMPLE.parse::<SensorField>().unwrap();
+		let regions = field.uncovered_regions(&(0..=20), &(0..=20));
+		assert_eq!(1, regions.len());
+		assert_eq!(1, regions[0].area());
+		assert_eq!((14..=14, 11..=11), regions[0].bounding_box());
+
+		// A lone sensor in a wider rectangle leaves a ring around it
+		let lone = "Sensor at x=0, y=0: closest beacon is at x=1, y=0"
+			.parse::<SensorField>().unwrap();
+		let regions = lone.uncovered_regions(&(-2..=2), &(-2..=2));
+		assert_eq!(1, regions.len());
+		assert_eq!(20, regions[0].area());
+		assert!(regions[0].contains((-1, -1)) && !regions[0].contains((0, 0)));
+		assert_eq!(4, lone.uncovered_regions(&(-1..=1), &(-1..=1)).len());
+	}
+
+	#[test]
+	fn day15_render() {
+		let lone = "Sensor at x=0, y=0: closest beacon is at x=1, y=0"
+			.parse::<SensorField>().unwrap();
+		assert_eq!(".....\n..#..\n.#SB.\n..#..\n.....",
+			lone.render(&(-2..=2), &(-2..=2), 1));
+		assert_eq!("SB\n#.", lone.render(&(-1..=2), &(-1..=2), 2));
+		let image = lone.to_pgm(&(-2..=2), &(-2..=2), 1);
+		assert_eq!(b"P5\n5 5\n255\n".len() + 25, image.len());
+	}
 }
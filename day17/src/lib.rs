@@ -13,7 +13,38 @@
 #![deny(missing_docs)]
 #![deny(rustdoc::missing_crate_level_docs)]
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub mod render;
+
+/// How deep below the top of the tower air is looked for when searching for
+/// a cycle, see [`tower_height`]
+const SURFACE_DEPTH: usize = 256;
+
+/// The chamber from the puzzle : seven units wide, rocks appear two units
+/// away from the left wall and three units above the highest rock, and the
+/// five rock shapes fall in this order.
+pub const DEFAULT_SPEC: &str = "width: 7
+left: 2
+gap: 3
+
+####
+
+.#.
+###
+.#.
+
+..#
+..#
+###
+
+#
+#
+#
+#
+
+##
+##";
 
 /// Solve Advent of Code day 17 part one
 ///
@@ -32,167 +63,380 @@ use std::collections::HashSet;
 #[must_use]
 #[allow(clippy::missing_const_for_fn)]
 pub fn solve_part_one(data: &str) -> usize {
-	let orders = data.chars()
-		.map(Jet::try_from)
-		.collect::<Result<Vec<Jet>, String>>()
-		.expect("Parsing error");
+	let orders = parse_jets(data).expect("Parsing error");
 
-	let mut screen: Screen = Screen::new(orders);
+	let mut screen = Screen::new(ChamberSpec::default(), orders)
+		.expect("Valid chamber");
 
 	// Apply the stuff for one base cycle
-	for _ in 0..2022 {
-		screen.fall();
-	}
+	screen.drop_rocks(2022);
 	screen.get_height()
 }
 
-fn solve_cyclical(orders: Vec<Jet>, total: usize) -> usize {
-	// So... where's the cycle?
-	let order_cycle = orders.len();
-	let minimal_cycle = (order_cycle * 5) / gcd(order_cycle, 5);
-	// How many times does the cycle fit?
-	let how_many_cycles = total / minimal_cycle;
-	//println!("We determine the minimal cycle must be {minimal_cycle}");
-	//println!("It will appear at most {how_many_cycles} times.");
+/// Compute the height of the tower built by a number of falling rocks
+///
+/// # Arguments
+///
+///  - `spec` : the [`ChamberSpec`] describing the chamber and its rocks
+///  - `jets` : a `&str` holding the jet pattern, made of `<` and `>`
+///  - `pieces` : the number of rocks that fall
+///
+/// # Return value
+///
+/// This function returns the height of the tower once all `pieces` rocks have
+/// come to rest. Long simulations are shortened by looking for a cycle : once
+/// the same jet and the same rock come up while the air a rock could reach
+/// below the top of the tower has the same shape, the tower keeps growing the
+/// same way. Only the top few hundred rows are compared, even when some
+/// columns are never filled, so a cycle only counts if no rock went deeper
+/// than that while it went round. Towers where rocks keep falling deeper are
+/// simply simulated.
+///
+/// # Errors
+///
+/// Returns a `String` describing the problem if the jet pattern is invalid.
+pub fn tower_height(spec: &ChamberSpec, jets: &str, pieces: usize)
+	-> Result<usize, String> {
+	let orders = parse_jets(jets)?;
+	solve_cyclical(spec, orders, pieces)
+}
+
+/// Parse a jet pattern into a list of [`Jet`]s
+///
+/// Surrounding whitespace is ignored.
+///
+/// # Errors
+///
+/// Returns a `String` describing the problem if the pattern is empty or
+/// contains something other than `<` and `>`.
+pub fn parse_jets(data: &str) -> Result<Vec<Jet>, String> {
+	let jets = data.trim().chars()
+		.map(Jet::try_from)
+		.collect::<Result<Vec<Jet>, String>>()?;
+	if jets.is_empty() {
+		return Err(String::from("Empty jet pattern"));
+	}
+	Ok(jets)
+}
 
-	let mut screen: Screen = Screen::new(orders);
+fn solve_cyclical(spec: &ChamberSpec, orders: Vec<Jet>, total: usize)
+	-> Result<usize, String> {
+	let mut screen = Screen::new(spec.clone(), orders)?;
 
-	// Apply the stuff for one base cycle
-	for _ in 0..minimal_cycle {
+	// Every state of the chamber between two rocks, with how many rocks had
+	// fallen and how high the tower was at the time
+	let mut seen = HashMap::new();
+	// How many rocks had fallen when one last looked below the surface
+	let mut last_deep = 0;
+	while screen.felled_pieces() < total {
+		let spawn_top = screen.get_height();
 		screen.fall();
+		// A rock looks one row below where it rests
+		if spawn_top + 1 - screen.rest_bottom > SURFACE_DEPTH {
+			last_deep = screen.felled_pieces();
+		}
+		let key = (screen.jet_counter, screen.next_piece, screen.surface());
+		let (pieces, height) = (screen.felled_pieces(), screen.get_height());
+		if let Some(&(then_pieces, then_height)) = seen.get(&key)
+			.filter(|&&(then_pieces, _)| last_deep <= then_pieces) {
+			// Same jet, same rock, same reachable air, and no rock looked
+			// deeper than that air since then : from now on, the tower grows
+			// exactly as it did since then
+			let cycle = pieces - then_pieces;
+			let cycles = (total - pieces) / cycle;
+			screen.drop_rocks((total - pieces) % cycle);
+			return Ok(screen.get_height() + cycles * (height - then_height));
+		}
+		seen.insert(key, (pieces, height));
+	}
+	Ok(screen.get_height())
+}
+
+/// A rock shape, parsed from ASCII art
+///
+/// The art uses `#` for the cells of the rock and `.` for empty space, with
+/// the top of the rock on the first line, just like in the puzzle. Cells are
+/// stored as `(height, column)` pairs relative to the bottom left corner of
+/// the rock.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rock {
+	cells: Vec<(usize, usize)>,
+	width: usize,
+	height: usize,
+}
+
+impl Rock {
+	/// The cells of the rock, as `(height, column)` pairs from its bottom left
+	#[must_use]
+	pub fn cells(&self) -> &[(usize, usize)] {
+		&self.cells
+	}
+
+	/// The width of the rock
+	#[must_use]
+	pub const fn width(&self) -> usize {
+		self.width
+	}
+
+	/// The height of the rock
+	#[must_use]
+	pub const fn height(&self) -> usize {
+		self.height
+	}
+}
+
+impl std::str::FromStr for Rock {
+	type Err = String;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let lines = s.lines()
+			.map(str::trim_end)
+			.collect::<Vec<&str>>();
+		let height = lines.len();
+		let mut cells = Vec::new();
+		for (row, line) in lines.iter().enumerate() {
+			for (x, c) in line.chars().enumerate() {
+				match c {
+					// The first line is the top of the rock
+					'#' => cells.push((height - row - 1, x)),
+					'.' => {},
+					x => return Err(format!("Unknown rock character '{x}'"))
+				}
+			}
+		}
+		if cells.is_empty() {
+			return Err(String::from("Rock has no cell"));
+		}
+		// Empty rows and columns on the edges would make the rock float
+		let bottom = cells.iter().map(|c| c.0).min().expect("One cell");
+		let left = cells.iter().map(|c| c.1).min().expect("One cell");
+		for cell in &mut cells {
+			cell.0 -= bottom;
+			cell.1 -= left;
+		}
+		let width = cells.iter().map(|c| c.1).max().expect("One cell") + 1;
+		let height = cells.iter().map(|c| c.0).max().expect("One cell") + 1;
+		Ok(Self { cells, width, height })
 	}
-	let first_heights = screen.normalized_heights();
-	//println!("After one minimal cycle, heights are {:?}", first_heights);
-	let first_cycle_height = screen.get_height();
-	//println!("The height is {first_cycle_height}");
-	//println!("We felled {} pieces so far", screen.felled_pieces);
-
-	// How many cycles will it take before we see that pattern again?
-	let mut real_cycle: usize = 0;
-	for a in 0..(how_many_cycles-1) {
-		// Do a full cycle
-		for _ in 0..minimal_cycle {
-			screen.fall();
-			//println!("{:?}", screen.normalized_heights());
+}
+
+/// The description of a chamber : its width, where rocks appear, and which
+/// rocks fall in it
+///
+/// A specification is written as a header of `key: value` lines followed by
+/// the rock shapes, every block being separated by an empty line. The known
+/// keys are `width` (the width of the chamber), `left` (the distance between
+/// the left wall and a new rock) and `gap` (the distance between the highest
+/// rock and a new rock). Missing keys take the values from the puzzle, and the
+/// header can be left out entirely. See [`DEFAULT_SPEC`] for an example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChamberSpec {
+	width: usize,
+	left: usize,
+	gap: usize,
+	rocks: Vec<Rock>,
+}
+
+impl ChamberSpec {
+	/// Build a new chamber specification
+	///
+	/// # Arguments
+	///
+	///  - `width` : the width of the chamber
+	///  - `left` : how far from the left wall new rocks appear
+	///  - `gap` : how far above the highest rock new rocks appear
+	///  - `rocks` : the rock shapes, in the order they fall
+	///
+	/// # Errors
+	///
+	/// Returns a `String` describing the problem if there is no rock, or if
+	/// a rock does not fit in the chamber when it appears.
+	pub fn new(width: usize, left: usize, gap: usize, rocks: Vec<Rock>)
+		-> Result<Self, String> {
+		if rocks.is_empty() {
+			return Err(String::from("No rock shape in specification"));
 		}
-		// Get the heights
-		let then_heights = screen.normalized_heights();
-		if then_heights == first_heights {
-			real_cycle = a+1;
-			break;
+		if let Some((idx, _)) = rocks.iter().enumerate()
+			.find(|(_, r)| left + r.width() > width) {
+			return Err(format!("Rock {idx} does not fit in a chamber of width {width}"));
 		}
+		Ok(Self { width, left, gap, rocks })
 	}
-	// Get the height for one full cycle
-	let full_cycle_height = screen.get_height() - first_cycle_height;
-	//println!("We find that the full cycle is actually {} times the smaller one",
-	//		 real_cycle);
-	//println!("That is to say, it is {} pieces", real_cycle * minimal_cycle);
-
-	// Now, how many times does the full cycle appear?
-	let how_many_full_cycles = (total - minimal_cycle) / (real_cycle * minimal_cycle);
-	//println!("There will only be {how_many_full_cycles} full cycles");
-	//println!("Each of which accounts for {full_cycle_height} of height");
-
-	// What will be the remainder?
-	let remainder = (total - minimal_cycle) % (real_cycle * minimal_cycle);
-	//println!("The remainder will be {remainder}");
-	for _ in 0..remainder {
-		screen.fall();
+
+	/// The width of the chamber
+	#[must_use]
+	pub const fn width(&self) -> usize {
+		self.width
+	}
+
+	/// The distance between the left wall and a new rock
+	#[must_use]
+	pub const fn left(&self) -> usize {
+		self.left
 	}
-	// Get the remainder
-	let remainder_height = screen.get_height() - (full_cycle_height + first_cycle_height);
-	//println!("And adds {remainder_height} of height");
 
-	remainder_height + first_cycle_height + full_cycle_height * how_many_full_cycles
+	/// The distance between the highest rock and a new rock
+	#[must_use]
+	pub const fn gap(&self) -> usize {
+		self.gap
+	}
+
+	/// The rock shapes, in the order they fall
+	#[must_use]
+	pub fn rocks(&self) -> &[Rock] {
+		&self.rocks
+	}
 }
 
-const fn gcd(mut a: usize, mut b: usize) -> usize {
-	while a != 0 {
-		let prev_a = a;
-		a = b % a;
-		b = prev_a;
+impl Default for ChamberSpec {
+	fn default() -> Self {
+		DEFAULT_SPEC.parse().expect("Valid default specification")
 	}
-	b
 }
 
-struct Screen {
-	heights: [usize; 7],
+impl std::str::FromStr for ChamberSpec {
+	type Err = String;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut blocks = s.trim().split("\n\n").peekable();
+		let (mut width, mut left, mut gap) = (7, 2, 3);
+
+		// Is there a header?
+		if blocks.peek().is_some_and(|b| b.contains(':')) {
+			let header = blocks.next().expect("Peeked");
+			for line in header.lines() {
+				let (key, value) = line.split_once(':')
+					.ok_or_else(|| format!("Invalid header line '{line}'"))?;
+				let value = value.trim().parse::<usize>()
+					.map_err(|e| format!("Invalid value for '{}': {e}", key.trim()))?;
+				match key.trim() {
+					"width" => width = value,
+					"left" => left = value,
+					"gap" => gap = value,
+					x => return Err(format!("Unknown header key '{x}'"))
+				}
+			}
+		}
+
+		let rocks = blocks
+			.map(str::parse::<Rock>)
+			.collect::<Result<Vec<Rock>, String>>()?;
+		Self::new(width, left, gap, rocks)
+	}
+}
+
+/// The chamber in which rocks fall, pushed around by jets of hot gas
+pub struct Screen {
+	heights: Vec<usize>,
 	next_piece: usize,
 	jets: Vec<Jet>,
-	jet_len: usize,
 	jet_counter: usize,
 	felled_pieces: usize,
-	tetrominos: [Vec<(usize, usize)>; 5],
-	memory: HashSet<(usize, usize)>
+	spec: ChamberSpec,
+	memory: HashSet<(usize, usize)>,
+	falling: Option<Vec<(usize, usize)>>,
+	push_next: bool,
+	// The lowest row of the last rock that came to rest
+	rest_bottom: usize,
 }
 
 impl Screen {
-	fn new(jets: Vec<Jet>) -> Self {
-		let jlen = jets.len();
-		Self {
-			heights: [0; 7],
+	/// Build an empty chamber
+	///
+	/// # Arguments
+	///
+	///  - `spec` : the [`ChamberSpec`] describing the chamber
+	///  - `jets` : the jet pattern, repeated for as long as rocks fall
+	///
+	/// # Errors
+	///
+	/// Returns a `String` if the jet pattern is empty.
+	pub fn new(spec: ChamberSpec, jets: Vec<Jet>) -> Result<Self, String> {
+		if jets.is_empty() {
+			return Err(String::from("Empty jet pattern"));
+		}
+		Ok(Self {
+			heights: vec![0; spec.width()],
 			next_piece: 0,
 			jets,
-			jet_len: jlen,
 			jet_counter: 0,
 			felled_pieces: 0,
-			tetrominos: [
-				vec![(0, 0), (0, 1), (0, 2), (0, 3)], // ####
-				vec![
-					/*   */ (2, 1), /*   */
-					(1, 0), (1, 1), (1, 2),
-					/*   */ (0, 1)  /*   */
-				],
-				vec![
-					/*   */ /*   */ (2, 2),
-					/*   */ /*   */ (1, 2),
-					(0, 0), (0, 1), (0, 2),
-				],
-				vec![
-					(3, 0),
-					(2, 0),
-					(1, 0),
-					(0, 0)
-				],
-				vec![
-					(1, 0), (1, 1),
-					(0, 0), (0, 1)
-				]
-			],
+			spec,
 			memory: HashSet::new(),
+			falling: None,
+			push_next: false,
+			rest_bottom: 0,
+		})
+	}
+
+	/// The air a falling rock could reach down to [`SURFACE_DEPTH`], as
+	/// `(depth, column)` pairs below the top of the tower
+	///
+	/// Rocks only ever move left, right and down, so two chambers with the
+	/// same reachable air behave the same for as long as rocks do not go
+	/// deeper, whatever lies below it.
+	fn surface(&self) -> Vec<(usize, usize)> {
+		let top = self.get_height();
+		let width = self.spec.width();
+		let mut queue = (0..width).map(|x| (top, x)).collect::<VecDeque<_>>();
+		let mut reached = queue.iter().copied().collect::<HashSet<_>>();
+		while let Some((y, x)) = queue.pop_front() {
+			let neighbours = [
+				y.checked_sub(1).filter(|&y| top - y <= SURFACE_DEPTH).map(|y| (y, x)),
+				x.checked_sub(1).map(|x| (y, x)),
+				(x + 1 < width).then_some((y, x + 1)),
+			];
+			for cell in neighbours.into_iter().flatten() {
+				if !self.memory.contains(&cell) && reached.insert(cell) {
+					queue.push_back(cell);
+				}
+			}
 		}
+		let mut surface = reached.into_iter()
+			.map(|(y, x)| (top - y, x))
+			.collect::<Vec<(usize, usize)>>();
+		surface.sort_unstable();
+		surface
 	}
 
-	/// Normalized heights that are memorized by the [`Screen`] structure
-	fn normalized_heights(&self) -> [usize; 7] {
-		// Get the heights and remove as much from them as possible
-		let heights = self.heights;
-		let min_height = heights.iter().min().unwrap();
-		heights.into_iter()
-			.map(|u| u - min_height)
-			.collect::<Vec<usize>>()
-			.try_into()
-			.expect("7 width")
+	/// Let the next rock fall until it comes to rest
+	///
+	/// If a rock is already falling, it is the one that finishes its fall.
+	pub fn fall(&mut self) {
+		while self.step() != Step::Rest {}
 	}
 
-	fn fall(&mut self) {
-		// What height are we at?
-		let height = self.get_height()+3;
-		// Spawn the piece
-		let mut rocks = self.tetrominos[self.next_piece]
-			.iter()
-			.copied()
-			.map(|(y, x)| (y+height, x+2))
-			.collect::<Vec<(usize, usize)>>();
-		
-		// Drop it by...
-		loop {
+	/// Advance the simulation by a single step
+	///
+	/// A new rock appears if none is falling. Otherwise, the falling rock is
+	/// alternately pushed by a jet and moved down one unit, until it cannot
+	/// go down anymore and comes to rest.
+	///
+	/// # Return value
+	///
+	/// Returns the [`Step`] that was just performed.
+	pub fn step(&mut self) -> Step {
+		let Some(mut rocks) = self.falling.take() else {
+			// What height are we at?
+			let height = self.get_height() + self.spec.gap();
+			let left = self.spec.left();
+			// Spawn the piece
+			self.falling = Some(self.spec.rocks()[self.next_piece]
+				.cells()
+				.iter()
+				.copied()
+				.map(|(y, x)| (y+height, x+left))
+				.collect::<Vec<(usize, usize)>>());
+			self.push_next = true;
+			return Step::Spawn;
+		};
+
+		if self.push_next {
 			// Check the current jet order
 			let cur_order = self.jets[self.jet_counter];
 			// Change the jet counter...
 			self.jet_counter += 1;
-			self.jet_counter %= self.jet_len;
+			self.jet_counter %= self.jets.len();
 			// Check that the jet is applicable
+			let right_wall = self.spec.width() - 1;
 			let mut jet_applicable = true;
 			for rock in &rocks {
 				if cur_order == Jet::Left && rock.1 == 0 {
@@ -200,7 +444,7 @@ impl Screen {
 					break;
 				}
 
-				if cur_order == Jet::Right && rock.1 == 6 {
+				if cur_order == Jet::Right && rock.1 == right_wall {
 					jet_applicable = false;
 					break;
 				}
@@ -219,8 +463,6 @@ impl Screen {
 
 			// If applicable, apply the jet
 			if jet_applicable {
-				////println!("Applied jet {}",
-						 //if cur_order == Jet::Left { "left" } else { "right" });
 				for rock in &mut rocks {
 					if cur_order == Jet::Left {
 						rock.1 -= 1;
@@ -229,59 +471,177 @@ impl Screen {
 					}
 				}
 			}
+			self.falling = Some(rocks);
+			self.push_next = false;
+			return Step::Push(cur_order, jet_applicable);
+		}
 
-			// Check whether we can go down
-			let mut go_down = true;
-			for rock in &rocks {
-				if rock.0 == 0 {
-					go_down = false;
-					break;
-				}
+		// Check whether we can go down
+		let mut go_down = true;
+		for rock in &rocks {
+			if rock.0 == 0 {
+				go_down = false;
+				break;
+			}
 
-				let dropped_rock = (rock.0 - 1, rock.1);
-				if self.memory.contains(&dropped_rock) {
-					go_down = false;
-					break;
-				}
+			let dropped_rock = (rock.0 - 1, rock.1);
+			if self.memory.contains(&dropped_rock) {
+				go_down = false;
+				break;
 			}
+		}
 
-			// If we can't, immobilize
-			if !go_down {
-				for rock in &rocks {
-					let (height, x) = rock;
-					// Change heights
-					self.heights[*x] = self.heights[*x].max(height+1);
-					// Memorize
-					//assert!(!self.memory.contains(&rock));
-					self.memory.insert(*rock);
-				}
-				//
-				////println!("Added {rocks:?}");
-				////println!("heights are now {:?}\n", self.heights);
-				// Change the tetromino counter...
-				self.next_piece += 1;
-				self.next_piece %= 5;
-				// Count
-				self.felled_pieces += 1;
-				// Return
-				return;
+		// If we can't, immobilize
+		if !go_down {
+			for rock in &rocks {
+				let (height, x) = rock;
+				// Change heights
+				self.heights[*x] = self.heights[*x].max(height+1);
+				// Memorize
+				self.memory.insert(*rock);
 			}
-			// if we can
-			// Apply down
-			rocks.iter_mut()
-				.for_each(|rock| rock.0 -= 1);
-			////println!("Standing {rocks:?}");
+			// Change the tetromino counter...
+			self.next_piece += 1;
+			self.next_piece %= self.spec.rocks().len();
+			// Count
+			self.rest_bottom = rocks.iter().map(|r| r.0).min().unwrap_or(0);
+			self.felled_pieces += 1;
+			return Step::Rest;
 		}
+		// if we can
+		// Apply down
+		for rock in &mut rocks {
+			rock.0 -= 1;
+		}
+		self.falling = Some(rocks);
+		self.push_next = true;
+		Step::Fall
+	}
+
+	/// Let `count` rocks fall one after the other
+	pub fn drop_rocks(&mut self, count: usize) {
+		for _ in 0..count {
+			self.fall();
+		}
+	}
+
+	/// The height of the tower of rocks
+	#[must_use]
+	pub fn get_height(&self) -> usize {
+		self.heights.iter().copied().max().unwrap_or(0)
+	}
+
+	/// How many rocks have come to rest so far
+	#[must_use]
+	pub const fn felled_pieces(&self) -> usize {
+		self.felled_pieces
+	}
+
+	/// The [`ChamberSpec`] describing this chamber
+	#[must_use]
+	pub const fn spec(&self) -> &ChamberSpec {
+		&self.spec
+	}
+
+	/// The height of the chamber worth showing : the tower, plus the falling
+	/// rock if there is one
+	#[must_use]
+	pub fn top(&self) -> usize {
+		self.falling.iter()
+			.flatten()
+			.map(|c| c.0 + 1)
+			.fold(self.get_height(), usize::max)
+	}
+
+	/// What can be found at height `y` and column `x` of the chamber
+	#[must_use]
+	pub fn tile(&self, y: usize, x: usize) -> Tile {
+		if self.memory.contains(&(y, x)) {
+			Tile::Rock
+		} else if self.falling.as_ref().is_some_and(|f| f.contains(&(y, x))) {
+			Tile::Falling
+		} else {
+			Tile::Air
+		}
+	}
+
+	/// Draw the chamber in the puzzle's notation
+	///
+	/// # Arguments
+	///
+	///  - `rows` : if set, only draw that many rows from the top of the chamber
+	///
+	/// The floor is only drawn when it is visible.
+	///
+	/// # Return value
+	///
+	/// Returns a `String` with one line per row, `#` being rocks at rest and
+	/// `@` the falling rock.
+	#[must_use]
+	pub fn render(&self, rows: Option<usize>) -> String {
+		let top = self.top();
+		let bottom = rows.map_or(0, |r| top.saturating_sub(r));
+		let mut lines = (bottom..top).rev()
+			.map(|y| {
+				let row = (0..self.spec.width())
+					.map(|x| char::from(self.tile(y, x)))
+					.collect::<String>();
+				format!("|{row}|")
+			})
+			.collect::<Vec<String>>();
+		if bottom == 0 {
+			lines.push(format!("+{}+", "-".repeat(self.spec.width())));
+		}
+		lines.join("\n")
+	}
+}
+
+impl std::fmt::Display for Screen {
+	fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(fmt, "{}", self.render(None))
 	}
+}
+
+/// A single step of the simulation, as returned by [`Screen::step`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Step {
+	/// A new rock appeared
+	Spawn,
+	/// The falling rock was pushed by a jet, and moved if the flag is set
+	Push(Jet, bool),
+	/// The falling rock went down one unit
+	Fall,
+	/// The falling rock could not go down and came to rest
+	Rest,
+}
 
-	fn get_height(&self) -> usize {
-		*self.heights.iter().max().unwrap()
+/// What can be found in a cell of the chamber
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Tile {
+	/// Empty space
+	Air,
+	/// Part of a rock at rest
+	Rock,
+	/// Part of the falling rock
+	Falling,
+}
+
+impl From<Tile> for char {
+	fn from(tile: Tile) -> Self {
+		match tile {
+			Tile::Air => '.',
+			Tile::Rock => '#',
+			Tile::Falling => '@',
+		}
 	}
 }
 
-#[derive(Copy, Clone, Eq, PartialEq)]
-enum Jet {
+/// A jet of hot gas, pushing a falling rock sideways
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Jet {
+	/// A jet pushing to the left, written `<`
 	Left,
+	/// A jet pushing to the right, written `>`
 	Right,
 }
 
@@ -313,13 +673,11 @@ impl TryFrom<char> for Jet {
 #[must_use]
 #[allow(clippy::missing_const_for_fn)]
 pub fn solve_part_two(data: &str) -> usize {
-	let orders = data.chars()
-		.map(Jet::try_from)
-		.collect::<Result<Vec<Jet>, String>>()
-		.expect("Parsing error");
+	let orders = parse_jets(data).expect("Parsing error");
 
 	// Apply the stuff it's pretty straightforward
-	solve_cyclical(orders, 1_000_000_000_000_usize)
+	solve_cyclical(&ChamberSpec::default(), orders, 1_000_000_000_000_usize)
+		.expect("Valid chamber")
 }
 
 // vim: set tw=80:
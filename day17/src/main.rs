@@ -21,4 +21,78 @@ mod test {
 
 	test!(day17_01_example1, 1, 3068, ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>");
 	test!(day17_02_example1, 2, 1_514_285_714_288, ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>");
+
+	use day17::{parse_jets, tower_height, ChamberSpec, Screen, DEFAULT_SPEC};
+	use day17::render::{to_ppm, trace, FrameRate};
+
+	#[test]
+	fn day17_spec_default() {
+		let spec = DEFAULT_SPEC.parse::<ChamberSpec>().unwrap();
+		assert_eq!(spec, ChamberSpec::default());
+		assert_eq!(Ok(3068), tower_height(&spec,
+			">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>", 2022));
+	}
+
+	#[test]
+	fn day17_spec_custom() {
+		// Flat bars filling the whole chamber just pile up
+		let spec = "width: 4\nleft: 0\n\n####".parse::<ChamberSpec>().unwrap();
+		assert_eq!(Ok(1_000_000), tower_height(&spec, "<>", 1_000_000));
+		// Without a header, the puzzle's chamber is used
+		let spec = "##\n##".parse::<ChamberSpec>().unwrap();
+		assert_eq!(7, spec.width());
+		assert_eq!(Ok(4), tower_height(&spec, "<<<>", 2));
+	}
+
+	#[test]
+	fn day17_spec_cycle() {
+		// The shape of the tower repeats long before the jets and rocks do
+		let spec = "width: 4\nleft: 0\n\n###\n\n#.\n##".parse::<ChamberSpec>().unwrap();
+		for (jets, rocks) in [("><>><", 376), ("<<>", 1000), ("><<>>>", 777)] {
+			let mut screen = Screen::new(spec.clone(), parse_jets(jets).unwrap())
+				.unwrap();
+			screen.drop_rocks(rocks);
+			assert_eq!(Ok(screen.get_height()), tower_height(&spec, jets, rocks));
+		}
+	}
+
+	#[test]
+	fn day17_spec_open_columns() {
+		// Pushed to the left wall, rocks never fill the last three columns
+		let spec = ChamberSpec::default();
+		let mut screen = Screen::new(spec.clone(), parse_jets("<").unwrap()).unwrap();
+		screen.drop_rocks(5000);
+		let height = tower_height(&spec, "<", 5000).unwrap();
+		assert_eq!(screen.get_height(), height);
+		let group = height - tower_height(&spec, "<", 4995).unwrap();
+		assert_eq!(Ok(height + (1_000_000_000_000 - 5000) / 5 * group),
+			tower_height(&spec, "<", 1_000_000_000_000));
+	}
+
+	#[test]
+	fn day17_spec_invalid() {
+		assert!("width: 3\n\n####".parse::<ChamberSpec>().is_err());
+		assert!("width: 7\n\n#x#".parse::<ChamberSpec>().is_err());
+		assert!("width: 7".parse::<ChamberSpec>().is_err());
+		assert!(tower_height(&ChamberSpec::default(), "<^>", 10).is_err());
+	}
+
+	#[test]
+	fn day17_render_steps() {
+		let jets = parse_jets(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>").unwrap();
+		let mut screen = Screen::new(ChamberSpec::default(), jets).unwrap();
+		let frames = trace(&mut screen, 1, FrameRate::Step, None);
+		assert_eq!(9, frames.len());
+		assert_eq!("|..@@@@.|\n|.......|\n|.......|\n|.......|\n+-------+", frames[0]);
+		assert_eq!("|...@@@@|\n|.......|\n|.......|\n|.......|\n+-------+", frames[1]);
+		assert_eq!("|..####.|\n+-------+", frames[8]);
+
+		let frames = trace(&mut screen, 1, FrameRate::Rock, Some(2));
+		assert_eq!(vec!["|...#...|\n|..###..|"], frames);
+
+		// 9 by 5 cells, 2 by 2 pixels each
+		let image = to_ppm(&screen, Some(4), 2);
+		assert!(image.starts_with(b"P6\n18 10\n255\n"));
+		assert_eq!(b"P6\n18 10\n255\n".len() + 18 * 10 * 3, image.len());
+	}
 }
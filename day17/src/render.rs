@@ -0,0 +1,206 @@
+//! Frame by frame rendering of the falling rocks
+//!
+//! Frames are drawn in the puzzle's notation, and can either be collected as
+//! text or written to a directory as a numbered sequence of text or PPM files
+//! that can be turned into an animation.
+
+use std::{
+	convert::Infallible,
+	fs,
+	io,
+	path::PathBuf,
+};
+
+use super::{Screen, Step};
+
+/// How often a frame is taken
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FrameRate {
+	/// One frame every time a rock comes to rest
+	Rock,
+	/// One frame for every step of the simulation : spawns, jet pushes and
+	/// falls
+	Step,
+}
+
+/// The format of the frames written by a [`FrameWriter`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FrameFormat {
+	/// Plain text, in the puzzle's notation
+	Text,
+	/// A binary PPM image, where every cell of the chamber is a `scale` by
+	/// `scale` square
+	Ppm {
+		/// The size of a cell in pixels
+		scale: usize
+	},
+}
+
+impl FrameFormat {
+	const fn extension(self) -> &'static str {
+		match self {
+			Self::Text => "txt",
+			Self::Ppm { .. } => "ppm",
+		}
+	}
+}
+
+/// Let rocks fall, calling `f` on the chamber for every frame
+fn run<E, F>(screen: &mut Screen, rocks: usize, rate: FrameRate, mut f: F)
+	-> Result<(), E>
+	where F: FnMut(&Screen) -> Result<(), E> {
+	for _ in 0..rocks {
+		loop {
+			let step = screen.step();
+			if rate == FrameRate::Step || step == Step::Rest {
+				f(screen)?;
+			}
+			if step == Step::Rest {
+				break;
+			}
+		}
+	}
+	Ok(())
+}
+
+/// Let rocks fall and collect the frames in the puzzle's notation
+///
+/// # Arguments
+///
+///  - `screen` : the [`Screen`] in which the rocks fall
+///  - `rocks` : how many rocks fall
+///  - `rate` : how often a frame is taken
+///  - `rows` : if set, how many rows from the top of the chamber are drawn
+///
+/// # Return value
+///
+/// Returns a `Vec<String>` holding every frame, in order.
+pub fn trace(screen: &mut Screen, rocks: usize, rate: FrameRate,
+			 rows: Option<usize>) -> Vec<String> {
+	let mut frames = Vec::new();
+	run(screen, rocks, rate, |s| {
+		frames.push(s.render(rows));
+		Ok(())
+	}).unwrap_or_else(|never: Infallible| match never {});
+	frames
+}
+
+/// Draw the chamber as a binary PPM image
+///
+/// Walls and floor are white, rocks at rest are grey, the falling rock is
+/// orange and the air is black.
+///
+/// # Arguments
+///
+///  - `screen` : the [`Screen`] to draw
+///  - `rows` : if set, how many rows from the top of the chamber are drawn
+///  - `scale` : the size of a cell in pixels
+///
+/// # Return value
+///
+/// Returns the bytes of the image.
+#[must_use]
+pub fn to_ppm(screen: &Screen, rows: Option<usize>, scale: usize) -> Vec<u8> {
+	let text = screen.render(rows);
+	let lines = text.lines()
+		.map(|l| l.chars().collect::<Vec<char>>())
+		.collect::<Vec<Vec<char>>>();
+	let width = lines.first().map_or(0, Vec::len);
+
+	let mut image = format!("P6\n{} {}\n255\n",
+		width * scale, lines.len() * scale).into_bytes();
+	for line in &lines {
+		let pixels = line.iter()
+			.flat_map(|&c| {
+				let colour: [u8; 3] = match c {
+					'#' => [0x80, 0x80, 0x80],
+					'@' => [0xff, 0x8c, 0x00],
+					'.' => [0x00, 0x00, 0x00],
+					_ => [0xff, 0xff, 0xff],
+				};
+				std::iter::repeat_n(colour, scale).flatten()
+			})
+			.collect::<Vec<u8>>();
+		for _ in 0..scale {
+			image.extend_from_slice(&pixels);
+		}
+	}
+	image
+}
+
+/// Writes numbered frames of a simulation to a directory
+///
+/// Frames are named `frame_000000.txt`, `frame_000001.txt`, and so on (or
+/// `.ppm`), so that they sort in the order they were taken.
+#[derive(Debug, Clone)]
+pub struct FrameWriter {
+	directory: PathBuf,
+	format: FrameFormat,
+	rows: Option<usize>,
+	counter: usize,
+}
+
+impl FrameWriter {
+	/// Build a new frame writer
+	///
+	/// # Arguments
+	///
+	///  - `directory` : where the frames are written, created if needed
+	///  - `format` : the [`FrameFormat`] of the frames
+	///  - `rows` : if set, how many rows from the top of the chamber are drawn
+	///
+	/// Setting `rows` keeps all the frames the same size once the tower is high
+	/// enough, which animation tools usually want.
+	pub fn new<P: Into<PathBuf>>(directory: P, format: FrameFormat,
+								 rows: Option<usize>) -> Self {
+		Self { directory: directory.into(), format, rows, counter: 0 }
+	}
+
+	/// How many frames were written so far
+	#[must_use]
+	pub const fn frames(&self) -> usize {
+		self.counter
+	}
+
+	/// Write the current state of the chamber as the next frame
+	///
+	/// # Return value
+	///
+	/// Returns the path of the written frame.
+	///
+	/// # Errors
+	///
+	/// In case of I/O exception, returns an Error.
+	pub fn write_frame(&mut self, screen: &Screen) -> io::Result<PathBuf> {
+		fs::create_dir_all(&self.directory)?;
+		let path = self.directory.join(format!("frame_{:06}.{}",
+			self.counter, self.format.extension()));
+		let contents = match self.format {
+			FrameFormat::Text => {
+				let mut text = screen.render(self.rows).into_bytes();
+				text.push(b'\n');
+				text
+			},
+			FrameFormat::Ppm { scale } => to_ppm(screen, self.rows, scale),
+		};
+		fs::write(&path, contents)?;
+		self.counter += 1;
+		Ok(path)
+	}
+
+	/// Let rocks fall and write a frame at the given rate
+	///
+	/// # Arguments
+	///
+	///  - `screen` : the [`Screen`] in which the rocks fall
+	///  - `rocks` : how many rocks fall
+	///  - `rate` : how often a frame is written
+	///
+	/// # Errors
+	///
+	/// In case of I/O exception, returns an Error.
+	pub fn record(&mut self, screen: &mut Screen, rocks: usize,
+				  rate: FrameRate) -> io::Result<()> {
+		run(screen, rocks, rate, |s| self.write_frame(s).map(|_| ()))
+	}
+}
@@ -13,8 +13,6 @@
 #![deny(missing_docs)]
 #![deny(rustdoc::missing_crate_level_docs)]
 
-use std::collections::HashMap;
-
 /// Solve Advent of Code day 20 part one
 ///
 /// # Arguments
@@ -31,140 +29,293 @@ use std::collections::HashMap;
 /// If any conversion assumed to be valid with the input fails, panics.
 #[must_use]
 pub fn solve_part_one(data: &str) -> isize {
-	let numbers = data.trim().split('\n')
-		.map(|s| s.parse::<isize>().expect("convertible"))
-		.enumerate()
-		.map(|(u, k)| (k, u))
-		.collect::<Vec<Clef>>();
+	Decryptor::new()
+		.decrypt(data)
+		.expect("Valid input")
+		.grove_sum()
+}
+
+/// The settings used to decrypt a file : a decryption key, a number of mixing
+/// rounds and the offsets of the grove coordinates
+///
+/// By default, the key is `1`, the file is mixed once, and the coordinates are
+/// read `1000`, `2000` and `3000` numbers after the zero, like in part one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decryptor {
+	key: isize,
+	rounds: usize,
+	offsets: Vec<usize>,
+}
+
+impl Default for Decryptor {
+	fn default() -> Self {
+		Self { key: 1, rounds: 1, offsets: vec![1000, 2000, 3000] }
+	}
+}
 
-	let mut ribbon = Ribbon::build(&numbers);
+impl Decryptor {
+	/// Build a decryptor with the settings of part one
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
 
-	data.trim().split('\n')
-		.map(|s| s.parse::<isize>().expect("convertible"))
-		.enumerate()
-		.for_each(|(u, k)| ribbon.mix((k, u)));
+	/// Set the decryption key every number is multiplied by before mixing
+	#[must_use]
+	pub const fn key(mut self, key: isize) -> Self {
+		self.key = key;
+		self
+	}
 
-	//println!("{ribbon}");
-	//println!("Len={}", ribbon.len());
-	let a = ribbon[1000];
-	let b = ribbon[2000];
-	let c = ribbon[3000];
+	/// Set how many times the file is mixed
+	#[must_use]
+	pub const fn rounds(mut self, rounds: usize) -> Self {
+		self.rounds = rounds;
+		self
+	}
 
-	//println!("a={a}, b={b}, c={c}");
+	/// Set the offsets after the zero at which the grove coordinates are read
+	#[must_use]
+	pub fn offsets(mut self, offsets: &[usize]) -> Self {
+		self.offsets = offsets.to_vec();
+		self
+	}
 
-	a + b + c
-}
+	/// Decrypt a file
+	///
+	/// # Arguments
+	///
+	///  - `data` : a `&str` holding one number per line
+	///
+	/// # Return value
+	///
+	/// Returns the [`Decrypted`] file, with the mixed numbers and the grove
+	/// coordinates.
+	///
+	/// # Errors
+	///
+	/// Returns a `String` describing the problem if a line is not a number,
+	/// if the file is empty, or if applying the key overflows.
+	pub fn decrypt(&self, data: &str) -> Result<Decrypted, String> {
+		let numbers = data.lines()
+			.enumerate()
+			.map(|(idx, s)| {
+				let number = s.trim().parse::<isize>()
+					.map_err(|e| format!("Line {}: {e}", idx + 1))?;
+				number.checked_mul(self.key)
+					.ok_or_else(|| format!("Line {}: overflow applying the key",
+						idx + 1))
+			})
+			.collect::<Result<Vec<isize>, String>>()?;
+		if numbers.is_empty() {
+			return Err(String::from("Empty file"));
+		}
 
-// Since it turns out that not all numbers are unique, we will use the pair of
-// (value, position in original list) as being a unique key
-type Clef = (isize, usize);
+		let mut mixer = CircularMixer::new(numbers);
+		for _ in 0..self.rounds {
+			mixer.mix();
+		}
 
-struct Ribbon {
-	data: HashMap<Clef, (Clef, Clef)>,
-	len: usize,
-	head: Clef,
+		let coordinates = self.offsets.iter()
+			.map(|&offset| mixer[offset])
+			.collect::<Vec<isize>>();
+		Ok(Decrypted { sequence: mixer.sequence(), coordinates })
+	}
 }
 
-impl Ribbon {
-	const fn len(&self) -> usize {
-		self.len
+/// A file decrypted by a [`Decryptor`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decrypted {
+	sequence: Vec<isize>,
+	coordinates: Vec<isize>,
+}
+
+impl Decrypted {
+	/// The mixed numbers, starting from the zero
+	#[must_use]
+	pub fn sequence(&self) -> &[isize] {
+		&self.sequence
+	}
+
+	/// The grove coordinates, in the order of the offsets
+	#[must_use]
+	pub fn coordinates(&self) -> &[isize] {
+		&self.coordinates
+	}
+
+	/// The sum of the grove coordinates
+	#[must_use]
+	pub fn grove_sum(&self) -> isize {
+		self.coordinates.iter().sum()
 	}
 }
 
-impl Ribbon {
-	fn mix(&mut self, key: Clef) {
-		let (num, _) = key;
-		if num == 0 { return; }
-
-		let size_signed: isize = (self.len - 1).try_into()
-			.expect("convertible");
-		let mut absolute_num: isize = isize::abs(num);
-		absolute_num %= size_signed;
-		if num < 0 {
-			// Going backwards, so change it
-			absolute_num = size_signed - absolute_num;
+/// A circular list of numbers that can be mixed
+///
+/// Mixing a number moves it forward in the list by as many positions as its
+/// value (backwards if negative), wrapping around. Numbers are identified by
+/// their position in the original list, so duplicates are moved separately,
+/// and indexing is done relative to the first zero of the original list.
+///
+/// The list is cut into about `√n` buckets, so that finding, removing and
+/// inserting a number all cost `O(√n)`. Buckets are rebuilt every `√n` moves
+/// to keep them balanced.
+#[derive(Debug, Clone)]
+pub struct CircularMixer {
+	values: Vec<isize>,
+	buckets: Vec<Vec<usize>>,
+	bucket_of: Vec<usize>,
+	bucket_size: usize,
+	moves: usize,
+	zero: Option<usize>,
+}
+
+impl CircularMixer {
+	/// Build a new mixer from the original list of numbers
+	#[must_use]
+	pub fn new(values: Vec<isize>) -> Self {
+		let zero = values.iter().position(|&v| v == 0);
+		let mut mixer = Self {
+			bucket_size: values.len().isqrt().max(1),
+			bucket_of: vec![0; values.len()],
+			buckets: vec![(0..values.len()).collect()],
+			moves: 0,
+			values,
+			zero,
+		};
+		mixer.rebalance();
+		mixer
+	}
+
+	/// The number of numbers in the list
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.values.len()
+	}
+
+	/// Whether the list is empty
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.values.is_empty()
+	}
+
+	/// Mix every number once, in the order of the original list
+	pub fn mix(&mut self) {
+		for id in 0..self.len() {
+			self.mix_one(id);
 		}
-		let mut other = key;
-		for _ in 0..absolute_num {
-			// Move forward by `absolute_num`
-			other = self.data[&other].1;
+	}
+
+	/// Mix a single number
+	///
+	/// # Arguments
+	///
+	///  - `id` : the position of the number in the original list
+	///
+	/// # Panics
+	///
+	/// Panics if `id` is not a position of the original list.
+	pub fn mix_one(&mut self, id: usize) {
+		let size = self.len();
+		// Nowhere to go
+		if size <= 1 { return; }
+
+		// Take the number out
+		let start = self.position_of(id);
+		let bucket = self.bucket_of[id];
+		let in_bucket = self.buckets[bucket].iter()
+			.position(|&x| x == id)
+			.expect("number in its bucket");
+		self.buckets[bucket].remove(in_bucket);
+
+		// Once it is out, there are only `size - 1` places left
+		let size_signed: isize = (size - 1).try_into().expect("convertible");
+		let shift: usize = self.values[id].rem_euclid(size_signed)
+			.try_into().expect("positive");
+		self.insert_at((start + shift) % (size - 1), id);
+
+		self.moves += 1;
+		if self.moves.is_multiple_of(self.bucket_size) {
+			self.rebalance();
 		}
+	}
+
+	/// The current position of a number in the list
+	///
+	/// # Panics
+	///
+	/// Panics if `id` is not a position of the original list.
+	#[must_use]
+	pub fn position_of(&self, id: usize) -> usize {
+		let bucket = self.bucket_of[id];
+		self.buckets[..bucket].iter().map(Vec::len).sum::<usize>()
+			+ self.buckets[bucket].iter()
+				.position(|&x| x == id)
+				.expect("number in its bucket")
+	}
+
+	/// The numbers of the list in their current order, starting from the first
+	/// zero of the original list if there is one
+	#[must_use]
+	pub fn sequence(&self) -> Vec<isize> {
+		let offset = self.zero.map_or(0, |z| self.position_of(z));
+		let ids = self.buckets.iter().flatten().copied().collect::<Vec<usize>>();
+		ids[offset..].iter()
+			.chain(&ids[..offset])
+			.map(|&id| self.values[id])
+			.collect()
+	}
 
-		// Now change this
-		let other_links = self.data[&other];
-		let my_links = self.data[&key];
-
-		self.data.insert(other, (other_links.0, key));
-		self.data.insert(key, (other, other_links.1));
-		// Stitch back the thing that followed "other"
-		let after_other = self.data[&other_links.1];
-		self.data.insert(other_links.1, (key, after_other.1));
-		// Stitch back where we removed "key"
-		let links_after = self.data[&my_links.1];
-		let links_before = self.data[&my_links.0];
-		self.data.insert(my_links.1, (my_links.0, links_after.1));
-		self.data.insert(my_links.0, (links_before.0, my_links.1));
-	} 
-
-	fn build(numbers: &[Clef]) -> Self {
-		let mut data: HashMap<Clef, (Clef, Clef)> = HashMap::new();
-
-		numbers[..]
-			.windows(3)
-			.for_each(|nums| {
-				let one = nums[0];
-				let two = nums[1];
-				let three = nums[2];
-
-				data.insert(two, (one, three));
-			});
-		let len: usize = numbers.len();
-		let head = (0, numbers.iter().position(|s| s.0 == 0).expect("one zero"));
-
-		// Tie up the loose ends
-		let list_head = numbers[0];
-		let list_tail = *numbers.last().expect("At least one number");
-		let tail_m_one = numbers[numbers.len()-2];
-		let head_p_one = numbers[1];
-
-		data.insert(list_head, (list_tail, head_p_one));
-		data.insert(list_tail, (tail_m_one, list_head));
-
-		Self { data, len, head }
+	fn insert_at(&mut self, mut index: usize, id: usize) {
+		for (bucket_idx, bucket) in self.buckets.iter_mut().enumerate() {
+			if index <= bucket.len() {
+				bucket.insert(index, id);
+				self.bucket_of[id] = bucket_idx;
+				return;
+			}
+			index -= bucket.len();
+		}
+		unreachable!("Index out of the list");
+	}
+
+	fn id_at(&self, mut index: usize) -> usize {
+		for bucket in &self.buckets {
+			if index < bucket.len() {
+				return bucket[index];
+			}
+			index -= bucket.len();
+		}
+		unreachable!("Index out of the list");
+	}
+
+	fn rebalance(&mut self) {
+		let ids = self.buckets.iter().flatten().copied().collect::<Vec<usize>>();
+		self.buckets = ids.chunks(self.bucket_size)
+			.map(<[usize]>::to_vec)
+			.collect();
+		for (bucket_idx, bucket) in self.buckets.iter().enumerate() {
+			for &id in bucket {
+				self.bucket_of[id] = bucket_idx;
+			}
+		}
 	}
 }
 
-impl std::fmt::Display for Ribbon {
+impl std::fmt::Display for CircularMixer {
 	fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		// ok
-		write!(fmt, "{:?}", (0..self.len())
-			   .map(|s| self[s].to_string()).collect::<Vec<String>>())
+		write!(fmt, "{:?}", self.sequence().iter()
+			   .map(ToString::to_string).collect::<Vec<String>>())
 	}
 }
 
-impl std::ops::Index<usize> for Ribbon {
+impl std::ops::Index<usize> for CircularMixer {
 	type Output = isize;
+	/// The number `idx` positions after the first zero of the original list,
+	/// wrapping around
 	fn index(&self, idx: usize) -> &Self::Output {
-		// First, don't run around needlessly
-		let rounded_idx = idx % self.data.len();
-		//// What way should we go?
-		if rounded_idx < self.data.len() / 2 {
-			// Go forward
-			let mut answer: &Clef = &self.head;
-			for _ in 0..rounded_idx {
-				answer = &self.data[answer].1;
-			}
-			&answer.0
-		} else {
-			// Go backwards
-			let rounded_idx = self.data.len() - rounded_idx;
-			let mut answer: &Clef = &self.head;
-			for _ in 0..rounded_idx {
-				answer = &self.data[answer].0;
-			}
-			&answer.0
-		}
-	} 
+		let offset = self.zero.map_or(0, |z| self.position_of(z));
+		&self.values[self.id_at((offset + idx) % self.len())]
+	}
 }
 
 /// Solve Advent of Code day 20 part two
@@ -184,28 +335,12 @@ impl std::ops::Index<usize> for Ribbon {
 #[must_use]
 #[allow(clippy::missing_const_for_fn)]
 pub fn solve_part_two(data: &str) -> isize {
-	let decryption_key = 811_589_153;
-	let numbers = data.trim().split('\n')
-		.map(|s| s.parse::<isize>().expect("convertible") * decryption_key)
-		.enumerate()
-		.map(|(u, k)| (k, u))
-		.collect::<Vec<Clef>>();
-
-	let mut ribbon = Ribbon::build(&numbers);
-
-	for _ in 0..10 {
-		numbers.iter().for_each(|&key| ribbon.mix(key));
-	}
-
-	//println!("{ribbon}");
-	//println!("Len={}", ribbon.len());
-	let a = ribbon[1000];
-	let b = ribbon[2000];
-	let c = ribbon[3000];
-
-	//println!("a={a}, b={b}, c={c}");
-
-	a + b + c
+	Decryptor::new()
+		.key(811_589_153)
+		.rounds(10)
+		.decrypt(data)
+		.expect("Valid input")
+		.grove_sum()
 }
 
 // vim: set tw=80:
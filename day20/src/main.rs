@@ -3,8 +3,7 @@
 
 extern crate common;
 use common::read_data;
-mod lib;
-use lib::{solve_part_one, solve_part_two};
+use day20::{solve_part_one, solve_part_two};
 
 #[doc(hidden)]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -23,4 +22,41 @@ mod test {
 	test!(day20_01_example1, 1, 3, "1\n2\n-3\n3\n-2\n0\n4");
 	test!(day20_01_sample2, 1, 2827, "6298\n5612\n-9787\n2909\n-6781\n-1977\n1446\n9428\n565\n-919\n-9067\n352\n6852\n6880\n-4626\n-1554\n7139\n-2630\n-8990\n8461\n1446\n-8777\n5401\n-1673\n-4419\n5889\n5529\n4216\n-5923\n9166\n734\n8823\n8712\n-677\n7155\n9596\n6187\n-3742\n3563\n6523\n1581\n-6485\n889\n-7263\n2097\n-961\n8655\n-8054\n4697\n118\n7274\n-3675\n4391\n1516\n-3010\n1593\n1624\n-7815\n-6792\n7463\n-6514\n-3489\n2975\n2077\n2504\n-5225\n-3167\n6149\n-3637\n5623\n3395\n-9436\n1417\n3859\n9049\n9219\n-6913\n5424\n-4626\n-5505\n-3326\n3418\n5388\n-5452\n-8804\n-9588\n-28\n3765\n-3616\n8896\n-5314\n3127\n-8040\n3778\n9671\n4001\n7290\n-7134\n-8248\n-1032\n-6371\n5642\n4504\n-6213\n-8022\n-1224\n5384\n-4696\n7248\n-9334\n6670\n3398\n-1004\n2041\n4971\n1221\n9839\n5718\n-8788\n5438\n2055\n-9410\n8455\n6375\n-8332\n8375\n-6372\n-1895\n6265\n-8\n7604\n-6460\n2936\n946\n-9212\n6468\n7270\n-1980\n2072\n-2176\n5431\n829\n-6919\n-6587\n-3229\n6731\n6249\n2394\n-4511\n-2041\n316\n9215\n6706\n-8474\n8944\n-1222\n-7880\n889\n7376\n-4343\n5998\n-8766\n13\n-7514\n9001\n7266\n9961\n-4633\n-6068\n9382\n6736\n9165\n3216\n9005\n-6797\n4984\n7673\n-913\n-5525\n-4127\n5305\n1511\n5305\n-5119\n-3610\n2253\n7526\n-7841\n-7393\n8647\n-1983\n-9356\n-193\n2489\n6013\n-1075\n8150\n4559\n-4665\n3454\n9889\n5725\n8260\n-9478\n5514\n2966\n-1692\n-323\n6828\n8701\n22\n13\n-3911\n-4624\n-7155\n5110\n-8543\n-5370\n-858\n-9135\n-2464\n5388\n2995\n-9053\n4001\n-8045\n3768\n4422\n-7837\n-9862\n326\n-4739\n309\n-8812\n551\n6948\n-9093\n-8764\n-2213\n3051\n-8082\n4545\n-3945\n1242\n-9895\n92\n-6724\n9088\n-6112\n-607\n-5689\n9281\n2937\n-8013\n-9891\n9039\n6155\n-1559\n-8069\n3980\n6528\n-9700\n-2105\n-3616\n-7984\n3508\n-6837\n2689\n-566\n373\n-4947\n2997\n-2813\n-4647\n7615\n6245\n-5062\n-4926\n6168\n7014\n-7515\n7699\n-7627\n-6673\n-9814\n-2352\n-6063\n2461\n-8199\n4806\n3882\n-8680\n-5199\n1465\n4048\n-9004\n-1159\n7525\n-6189\n-6128\n-3541\n-4952\n7539\n6543\n1690\n-433\n6090\n3511\n-7122\n-5175\n716\n4481\n8758\n-2512\n427\n-7946\n-7868\n-7712\n-3975\n7685\n-8083\n-4493\n-5267\n8947\n-5923\n-5481\n2009\n7841\n-1142\n2808\n6620\n6291\n-636\n-1792\n-7993\n6509\n-7880\n-7463\n-8247\n-7837\n-4604\n-1332\n-2356\n3038\n4041\n-2946\n-7705\n-9430\n-6464\n-5118\n8635\n3810\n1769\n6765\n8771\n-5611\n4806\n2436\n2867\n-7007\n-9073\n-5681\n3276\n7672\n-5097\n-9105\n242\n-2881\n-9309\n-5910\n-9761\n8991\n-8126\n-6747\n-1740\n2698\n-8739\n-7770\n2104\n-5514\n7999\n2861\n-4129\n2958\n9430\n7410\n-5713\n5944\n-8220\n-6624\n-2464\n4378\n-7880\n-178\n8981\n-9102\n869\n2743\n6947\n-8344\n7564\n7600\n6124\n-6431\n-1718\n312\n6389\n3961\n-5484\n2961\n2356\n-5603\n2130\n-3774\n-5102\n7283\n-3361\n-9946\n-7533\n-5275\n6902\n-8479\n-1760\n-3762\n-2627\n-470\n-5156\n-1527\n1800\n6357\n9861\n-2585\n-8812\n4189\n-5756\n8774\n5281\n6523\n8215\n-886\n-2456\n-7263\n2726\n2998\n7264\n-8788\n3213\n7014\n-6983\n-9840\n-3503\n-7025\n-4851\n3085\n7380\n5333\n-1305\n9009\n2314\n3524\n-6683\n6001\n-6022\n-585\n1818\n-7880\n9511\n1483\n6156\n-5551\n5711\n-108\n-7610\n-8298\n2703\n1487\n-6782\n2507\n-7649\n-4913\n537\n2161\n-5515\n-7868\n-1220\n4489\n-5504\n-5442\n235\n-9288\n-6470\n-6637\n7405\n298\n-2801\n1649\n-1452\n-2653\n-124\n8726\n-2015\n-706\n-9787\n5015\n5395\n1579\n4867\n9772\n853\n6\n-2706\n-2817\n-4287\n8103\n8213\n-5522\n5498\n-6800\n-3867\n4422\n2322\n3004\n4289\n8199\n-1277\n6444\n7772\n2019\n-3516\n-7339\n8320\n482\n4385\n-9442\n-4009\n-5258\n177\n7088\n-4511\n6962\n-9850\n316\n8964\n794\n6615\n7390\n1120\n9324\n6296\n9619\n-6934\n5169\n4568\n338\n3555\n-5305\n-9943\n-9369\n-9715\n1692\n-7267\n738\n-1263\n-7974\n-3167\n9834\n-1186\n6950\n-5665\n4007\n-693\n9156\n790\n-8669\n6470\n-8751\n9977\n7696\n-6349\n890\n8624\n-765\n-7754\n-1461\n-2538\n2581\n7056\n-1088\n1409\n-1116\n-9450\n-427\n-5922\n3045\n-2096\n-9864\n7835\n-7868\n-5457\n180\n-4061\n5685\n-4122\n-6185\n1909\n-2325\n-6479\n-1212\n2322\n2436\n-2619\n-1806\n2771\n-5832\n-2284\n-317\n2947\n-3220\n-4799\n1288\n6650\n159\n-205\n8460\n9352\n-5204\n4471\n-3135\n-9891\n7090\n7830\n-5521\n-1701\n-5437\n2070\n-8096\n8035\n-2566\n5814\n4430\n3485\n-1370\n8856\n-4243\n6833\n-8832\n-5701\n-6028\n9219\n-3330\n-9670\n5322\n7639\n-1839\n-678\n-549\n-4159\n5198\n5376\n-7774\n-7068\n-5823\n8352\n8592\n-79\n-2658\n-5346\n8532\n1982\n8221\n2969\n463\n-190\n1031\n-7537\n5329\n-7612\n6616\n4033\n-340\n-5413\n4741\n8969\n-3653\n-9905\n5960\n-3484\n1611\n-5493\n9819\n9466\n-3282\n-8523\n6383\n-9109\n-9914\n8054\n-7438\n7839\n-1545\n-2038\n7273\n-5718\n-5602\n-2960\n-4122\n-9785\n2660\n-6270\n-525\n2756\n-5377\n1775\n5268\n-5440\n1069\n-2398\n8274\n-3749\n-2619\n-9872\n8247\n3200\n0\n1355\n289\n1362\n-5501\n7675\n-5599\n7475\n8422\n-321\n7449\n-2231\n-6745\n-7244\n6919\n255\n6979\n-1701\n4966\n1180\n-3609\n5292\n1909\n6518\n-8305\n-9141\n6926\n-1706\n3765\n6906\n9450\n6964\n-6747\n-1584\n-2803\n-5678\n-5459\n-7861\n6295\n-7766\n7225\n-6815\n9081\n1531\n537\n-7619\n5261\n2406\n-2615\n-4063\n-7271\n-205\n-6806\n-8082\n-1241\n-8158\n-5581\n6313\n-3366\n-3319\n-2029\n1399\n270\n-9240\n-1110\n-9773\n2650\n105\n9274\n9309\n8031\n-7539\n2852\n781\n-9366\n3575\n-6822\n-7483\n-5492\n1473\n-8136\n-3066\n5648\n-5602\n-699\n1953\n-3743\n6440\n4359\n-114\n4499\n3024\n-96\n7772\n2009\n8622\n7886\n2638\n-5505\n1288\n-7474\n4208\n3975\n-4272\n1134\n4995\n55\n-2981\n-9117\n7701\n-7109\n901\n-114\n8221\n-564\n-7134\n-2175\n9316\n1820\n-7805\n-7339\n-2717\n-4571\n-447\n5454\n3276\n-4712\n6264\n-9388\n2079\n1751\n-7158\n3216\n-2629\n9320\n5408\n-7974\n-8783\n4216\n-6783\n5432\n-8336\n1446\n193\n-6268\n9767\n-5069\n8383\n3367\n-9405\n925\n1891\n8487\n6044\n-8359\n8674\n-1175\n8614\n1793\n2979\n7042\n2915\n-484\n-3423\n-5951\n1557\n-6356\n3479\n2032\n3443\n9975\n4357\n1544\n-5987\n-4135\n-686\n-6008\n4481\n-5679\n-6684\n8892\n3413\n-2581\n2816\n-5360\n-8964\n-7669\n7850\n-2375\n-4585\n9289\n-8638\n-2128\n-8063\n-4626\n-8168\n2896\n-9582\n4192\n468\n4998\n-8421\n-2520\n-5225\n3471\n6148\n4387\n-8332\n1985\n-878\n-585\n5290\n-6466\n-3642\n-7881\n-201\n4819\n-5372\n-2088\n-9578\n-6032\n749\n-1305\n497\n-6865\n-9527\n3926\n-6080\n9496\n8542\n-8185\n6149\n-4744\n5408\n2580\n6088\n-8570\n-2368\n-4383\n-4700\n-3547\n5305\n534\n-4720\n-4219\n-1549\n-7347\n-5417\n3281\n5708\n-201\n-789\n7743\n-4781\n-7093\n-7266\n145\n9352\n7799\n6508\n-354\n-9023\n-3743\n2070\n8343\n8789\n-8680\n2419\n-6140\n-8353\n9430\n-1252\n-7047\n9261\n1503\n-1622\n-6140\n7425\n-7738\n-4622\n-7122\n-5271\n456\n4188\n-1908\n687\n4378\n8017\n5934\n6148\n-3411\n4425\n407\n2025\n3360\n-360\n-231\n-3072\n4744\n-5406\n-2063\n8487\n-2444\n4252\n-3254\n8261\n-3297\n4324\n-7627\n1540\n-1792\n-5314\n-3536\n3178\n2314\n4932\n3415\n-3079\n-7984\n-2553\n8756\n-1789\n5850\n9360\n-6541\n-9068\n-2978\n-9071\n7402\n-6010\n6574\n8032\n9181\n890\n7953\n2072\n-7006\n-9445\n-5874\n6784\n-432\n-9475\n-7729\n9682\n-5521\n-5435\n2269\n-7965\n9828\n9309\n-1694\n8489\n-566\n-5592\n-1780\n-8828\n-4635\n-7880\n1524\n5357\n6537\n-6183\n803\n-7882\n-3793\n925\n-394\n40\n9587\n7014\n-8991\n-2878\n3200\n-6035\n9402\n5794\n3036\n-4825\n-6745\n-798\n-8725\n-5538\n8910\n4609\n-9761\n5394\n1912\n9210\n6440\n-5075\n-2883\n4381\n-6464\n-5226\n-8999\n1299\n255\n-9840\n5135\n5057\n-7285\n2857\n-8258\n-2439\n9151\n-2096\n9312\n9476\n4442\n2211\n-7778\n-2139\n-478\n3303\n-2130\n7312\n9446\n-6822\n365\n2739\n-4749\n-1599\n4990\n4176\n3524\n-1558\n-7964\n-4571\n4361\n3668\n-8217\n8488\n-5396\n1600\n-4777\n-7875\n-2441\n-605\n-1271\n2579\n3110\n8715\n5944\n4115\n9828\n-9394\n9834\n7830\n121\n4178\n-9838\n-7866\n-2465\n-2090\n4001\n3840\n-3264\n-2096\n3575\n2652\n8658\n-8736\n1876\n-7459\n-1481\n-9891\n-6028\n-1016\n8234\n-5505\n-3699\n488\n-1406\n9594\n6684\n-8662\n6413\n-5365\n5746\n-2014\n-2592\n9489\n-7391\n4909\n-4877\n1329\n-7249\n203\n-9802\n134\n4466\n-725\n9106\n7843\n-9665\n-2950\n-455\n-4545\n-6957\n4598\n-3552\n-3542\n-4689\n-8567\n-2017\n-8351\n-2483\n-7783\n394\n-3652\n-4862\n8149\n1622\n766\n6830\n-2688\n7717\n-3890\n-8366\n-6053\n-8873\n-7807\n-2136\n-7042\n-5429\n-832\n-2741\n1438\n4148\n-2431\n-704\n8098\n-104\n-1516\n7990\n-5844\n-7841\n2506\n2440\n-1956\n8058\n2138\n-7090\n5173\n8981\n-3435\n3402\n-7977\n2424\n2792\n-3456\n-1477\n-2326\n-6479\n-5983\n3445\n-3292\n3867\n9753\n-3630\n-5833\n-2041\n-6482\n-4170\n5638\n9535\n5388\n-1407\n7841\n3768\n5163\n5107\n5379\n7661\n7010\n5183\n5251\n-6094\n-625\n967\n-167\n5394\n3220\n-1808\n-9643\n9061\n-9520\n4145\n1738\n-6068\n8097\n4215\n-9293\n6899\n416\n-2869\n7561\n-195\n9548\n-3544\n8180\n4261\n-9581\n-3008\n6497\n-526\n1083\n-3917\n5922\n-9230\n-4954\n4705\n5944\n-9874\n-9623\n-984\n1754\n-5491\n2463\n3267\n-1815\n-4389\n-3044\n2803\n-4808\n628\n774\n4917\n3980\n-5814\n-4917\n-1071\n-4620\n-8661\n9274\n2806\n-5077\n2985\n7056\n-1643\n1919\n6187\n-171\n5106\n7581\n-922\n1317\n1689\n2823\n7857\n-326\n-2231\n-2050\n-673\n5060\n-2625\n-1225\n-1494\n7101\n-1015\n-9273\n5384\n2436\n-8943\n5661\n-7715\n-7924\n6815\n7919\n-1969\n-1581\n751\n4001\n4992\n44\n4723\n-7173\n-4174\n-2634\n3699\n324\n5349\n-277\n-3384\n-894\n2460\n-5515\n1586\n-8872\n-3675\n520\n6551\n-7541\n-8188\n-9685\n5691\n8215\n5601\n1548\n-7635\n3270\n-5267\n5765\n-129\n-4324\n-1435\n9161\n9377\n-7417\n-2882\n-2881\n4087\n1116\n-228\n-7448\n-255\n-3066\n-6765\n7905\n-2536\n-9566\n9008\n8539\n3789\n2891\n-6176\n9898\n6218\n6760\n-9082\n-9195\n-7097\n44\n7854\n-3345\n1652\n-2727\n4354\n7561\n-5695\n-1688\n5966\n-7658\n7867\n7859\n1121\n804\n2937\n-8297\n1933\n-6500\n9930\n1968\n1358\n7347\n-2981\n2823\n-3819\n9693\n-7240\n8144\n7653\n-231\n-2274\n-1276\n-7958\n9114\n-8179\n-6961\n-9483\n-2018\n5493\n6725\n4032\n3407\n8785\n3258\n5493\n-6419\n7775\n2581\n-2958\n-5036\n4422\n7958\n2991\n-7423\n4927\n2730\n1131\n559\n5011\n5850\n-1003\n-6913\n-4120\n-1001\n-193\n2803\n7403\n-9693\n-9542\n-4851\n2436\n-7880\n-4617\n-8744\n-2156\n3561\n6508\n-5683\n4699\n-6112\n4699\n4813\n-6599\n-5523\n-4789\n1450\n-9557\n-1475\n2705\n-5806\n-5468\n-5678\n1069\n-3560\n6234\n-8297\n6750\n4327\n-9784\n6864\n-2483\n709\n4820\n-8167\n1409\n454\n3428\n-1279\n-2653\n-6377\n-6116\n1363\n-5228\n4171\n969\n7120\n6501\n-1853\n9905\n-1601\n-3406\n-3434\n-8114\n8324\n2205\n3729\n-8271\n3740\n-9893\n4084\n2966\n9297\n-2582\n3616\n1417\n1562\n-6910\n-6166\n-159\n6554\n4167\n-7095\n-447\n-7867\n-9673\n-6082\n-1413\n-4585\n-9581\n4049\n5150\n-5009\n-5521\n-9375\n5297\n8420\n4483\n-1692\n-3259\n-8572\n9136\n9377\n-9891\n-3725\n-9971\n-8359\n3401\n8466\n-9479\n9601\n1197\n1240\n1511\n-9785\n7720\n-1006\n-1075\n-3342\n5251\n-3970\n8639\n6225\n-4789\n3601\n7616\n6762\n-4174\n774\n6524\n4752\n-7829\n8730\n3255\n-9426\n-2096\n162\n-5828\n2389\n-5611\n4327\n-1187\n555\n-2397\n168\n-5339\n-8761\n6024\n7895\n2966\n9320\n-567\n4763\n92\n-859\n9615\n8288\n9106\n-2050\n5638\n7090\n8969\n-6919\n4391\n-8317\n6736\n-6764\n-1465\n4054\n9251\n7272\n-4204\n2327\n-4177\n1861\n-1574\n2041\n-7713\n-8045\n-2100\n6940\n-4802\n-9968\n8631\n-3546\n138\n-7977\n1709\n6625\n5282\n2528\n-7503\n-1023\n-1032\n7136\n111\n9914\n1982\n-3609\n-1054\n-3404\n4789\n2956\n2650\n5246\n332\n2823\n4764\n2602\n7128\n-3917\n6145\n-7448\n-4696\n-3850\n-8823\n-5741\n-3831\n-6479\n-16\n4631\n4281\n6320\n1692\n-6267\n8065\n-1792\n-5224\n-6427\n2752\n1713\n6025\n-8142\n7472\n-1793\n3276\n8042\n1958\n4012\n4576\n8680\n1227\n-7445\n-5579\n5329\n-3532\n-322\n712\n-9296\n-9178\n7154\n-1482\n-6754\n-4057\n-7419\n6364\n6850\n-2569\n-9585\n-4027\n-2444\n4007\n-4164\n-9773\n-4716\n6660\n-322\n3312\n5639\n4579\n-849\n5177\n-3884\n-2614\n-7866\n5321\n-323\n-2847\n-9492\n8008\n-6299\n532\n2601\n5648\n-9310\n238\n365\n-1609\n7134\n-8818\n-7720\n5347\n3765\n729\n9284\n-8481\n790\n2080\n365\n297\n9977\n6339\n-583\n8752\n-7175\n-4930\n-5386\n7073\n2801\n-2592\n5058\n-1571\n3974\n3694\n3108\n5810\n-5679\n-4389\n-1538\n-2446\n-3296\n-2094\n-8268\n-2065\n-9404\n468\n8736\n8598\n-7459\n-5483\n-4817\n-5364\n4699\n5528\n-4217\n794\n-9399\n-730\n-5406\n4690\n1413\n-5741\n-9202\n2966\n-4061\n1479\n8567\n2143\n720\n-7015\n4447\n-9766\n-2079\n1516\n-902\n-858\n9699\n-4815\n8879\n5287\n2454\n-7263\n8981\n-4938\n-9555\n2651\n7576\n-8187\n5571\n-5470\n-988\n2638\n2689\n-4731\n2309\n-178\n-1593\n-3691\n-2131\n-6123\n-3552\n-4930\n-2061\n-4046\n-984\n6479\n4327\n-8083\n7226\n-2890\n-6474\n8180\n731\n-6471\n-8069\n-337\n-4599\n-4430\n-4061\n5359\n-1482\n-4404\n1196\n-7774\n-9095\n-79\n696\n8340\n-2736\n-3520\n-5556\n-7773\n3986\n-1384\n5560\n-8069\n-8184\n4083\n-3910\n-1817\n3414\n-6063\n2638\n517\n4369\n-5277\n-3408\n6706\n-7385\n-384\n5058\n-2806\n-3675\n-9458\n-3185\n1246\n-6079\n-4931\n-677\n-3281\n6322\n-858\n805\n-8957\n9092\n-7463\n2622\n-4880\n-78\n5226\n-6382\n1298\n-155\n3276\n9646\n255\n7545\n-1112\n-8282\n-7402\n5385\n-1981\n2538\n-2299\n3193\n-4304\n-3463\n2697\n-6486\n-80\n-3509\n-1315\n-7125\n5746\n1372\n-6704\n2623\n-393\n1409\n-2981\n-1861\n-6229\n-6514\n-4103\n-9055\n-9797\n-6903\n-6572\n-4590\n-1297\n-1725\n-8468\n7389\n2788\n-7074\n-4153\n6232\n9677\n7537\n3646\n-3884\n8978\n-1704\n8659\n-4585\n7319\n5432\n9160\n-3205\n-6684\n7027\n9066\n-7012\n1511\n-5769\n-4956\n2752\n6379\n9143\n3461\n6607\n4007\n-1384\n-1047\n6814\n8884\n3244\n-8194\n-9426\n1738\n2622\n-7649\n-5074\n3967\n-2828\n-4356\n4872\n3386\n6919\n9685\n6981\n252\n-3975\n-2050\n4047\n-6258\n-3282\n4192\n357\n-4520\n3842\n2216\n3531\n-3987\n8343\n-2119\n-4879\n-4685\n-5341\n-780\n7965\n2213\n-6112\n-6375\n3894\n-7525\n9001\n8826\n9063\n-4348\n235\n-3216\n-6444\n6537\n-4212\n-1340\n3303\n-9323\n5036\n-2497\n4527\n-9891\n1298\n9654\n-1928\n6337\n-9619\n-3070\n-7551\n-6675\n-4146\n2536\n4423\n-6206\n1143\n155\n8233\n4678\n538\n9485\n-3868\n9894\n6149\n8132\n-9493\n-6893\n2078\n-2781\n93\n5355\n-3520\n-8187\n2481\n-1735\n-3066\n-4032\n-7340\n8755\n3778\n9343\n-2716\n7859\n7279\n-5679\n2923\n-9671\n-7270\n-390\n-2781\n-3155\n554\n-9814\n4184\n-5854\n7661\n8995\n-9245\n5227\n-2451\n-625\n6308\n-4659\n-561\n-8823\n7696\n-1987\n-6574\n-625\n8902\n7073\n1747\n3570\n-8081\n-8749\n4872\n3276\n5731\n3623\n-9620\n-1331\n-8285\n3409\n-2416\n-8836\n-2089\n1920\n7154\n-8341\n-5428\n-9759\n8824\n-5308\n6218\n-2029\n203\n2966\n-4262\n-7760\n-7156\n1919\n-826\n9587\n6047\n1690\n2211\n-4208\n665\n1384\n-7011\n-4872\n-7087\n-3437\n9116\n1088\n-196\n-8\n565\n-3009\n3067\n-5821\n5060\n718\n-3962\n1605\n-6686\n6650\n3965\n-7811\n-2974\n8096\n-3443\n7604\n-7981\n-6375\n-5686\n8226\n6271\n-7202\n-8249\n-3484\n-1398\n-7036\n87\n8880\n-1361\n9570\n-7788\n-6842\n43\n-8332\n-9576\n-6482\n7471\n2880\n4250\n1143\n-3934\n2290\n475\n9650\n1031\n450\n-8831\n5146\n5501\n5286\n9549\n-4191\n-2164\n-2412\n-4430\n3771\n-4827\n5110\n2354\n-3539\n7447\n-3911\n9490\n-7783\n794\n4524\n5707\n1758\n255\n-7906\n-1420\n-4135\n-3846\n4073\n-9114\n-6803\n7839\n1622\n-3253\n-8761\n-588\n8216\n8065\n4191\n3187\n-8521\n-4581\n735\n-4009\n4757\n-7968\n-6762\n-7892\n-6189\n-8700\n-1043\n2765\n289\n-4657\n563\n4813\n-521\n-6268\n8290\n3884\n3495\n2335\n-491\n-7931\n-4173\n-7134\n-5636\n-6485\n-9785\n-897\n-7269\n5157\n3187\n-6715\n-1301\n9160\n-258\n1650\n411\n-2079\n9313\n-4824\n7345\n270\n-6039\n8762\n-6112\n-4788\n9085\n-1320\n-1214\n-9963\n-3879\n6974\n-2607\n4040\n-1081\n5122\n-3290\n9840\n-10\n-7271\n-189\n6650\n7978\n-3781\n6451\n-9118\n2689\n503\n-746\n-7018\n2923\n6044\n6745\n182\n-7806\n9709\n-3840\n6880\n-7434\n-4524\n7052\n-2805\n8532\n44\n-6681\n2335\n9680\n-3405\n-8580\n-8719\n5992\n497\n-1824\n5030\n-7894\n6441\n-5013\n-4601\n1106\n8085\n5305\n5394\n5698\n7115\n1754\n-1233\n-8521\n-5902\n4550\n5216\n-4744\n-1982\n4911\n2683\n4498\n4592\n3415\n2181\n-5062\n3790\n-6081\n-5226\n-2588\n-3893\n4874\n-5341\n-8483\n-8693\n-4596\n-9854\n3773\n-6591\n1372\n-1075\n-3688\n-9826\n822\n-9819\n-3008\n9906\n1791\n3616\n5542\n1614\n8816\n-8889\n4751\n-3669\n9450\n-7627\n9984\n5863\n3142\n-8657\n-2421\n9165\n-9371\n3492\n5329\n9012\n5395\n-4431\n-9957\n-952\n1818\n-4599\n-5946\n2340\n7024\n4423\n-1107\n-7616\n2420\n-7770\n-9015\n3364\n134\n5273\n-8327\n6590\n-2290\n-5504\n6998\n4642\n-3533\n4251\n-5769\n2239\n1422\n61\n2796\n8486\n-427\n-4601\n4257\n4218\n8321\n2937\n8199\n-6190\n-9640\n-7951\n255\n6316\n5811\n-9859\n-2015\n-4126\n-7634\n-4688\n2997\n8164\n9041\n-5147\n-3718\n-4335\n6640\n1855\n1367\n-7767\n9185\n-7783\n-337\n8428\n-7475\n-6637\n-8060\n5638\n-1908\n3477\n-326\n-7338\n-5110\n7632\n-8304\n-2873\n7374\n-3555\n7157\n-5809\n-1704\n7684\n8340\n-3176\n8368\n-3662\n7767\n6519\n9449\n5170\n-4374\n-6673\n-6083\n8074\n6651\n-9761\n7623\n423\n2998\n-263\n5988\n-1727\n6620\n-9850\n-6609\n8639\n-1115\n5799\n-8305\n3511\n1752\n-5695\n-3738\n-1020\n-6538\n9973\n-5481\n-3051\n-5522\n9861\n-9893\n-4375\n-472\n-4835\n2806\n-8900\n87\n-2537\n-5645\n5305\n-8719\n-2420\n-3945\n-1558\n6894\n-2808\n2997\n-1012\n-3698\n-2539\n8995\n509\n-3781\n5653\n6839\n1069\n7481\n9380\n-1806\n-2830\n138\n7305\n-1886\n3046\n8330\n-311\n9151\n-6945\n-8489\n5501\n7635\n-7977\n848\n9108\n6617\n8761\n9435\n-1480\n-7628\n-605\n2944\n3763\n3755\n357\n-5942\n4449\n3437\n4779\n-6654\n-6769\n-3975\n9639\n5984\n-5318\n-1330\n5431\n-2469\n4449\n-8828\n-7475\n2232\n-9891\n-6500\n-5077\n-1947\n-8404\n4937\n-326\n-4649\n-4739\n-5339\n8603\n-8154\n-3503\n6557\n2919\n-1554\n9145\n109\n-8418\n2978\n2561\n-1383\n3053\n5982\n-1301\n-3421\n-7054\n-3744\n-6546\n6265\n-6536\n4533\n-6532\n5297\n5629\n-2727\n1231\n4765\n2511\n-1956\n5251\n-2483\n-1483\n8936\n-4952\n-3344\n4240\n-2155\n-3421\n-3003\n6326\n-3240\n4448\n-8786\n3222\n2277\n6131\n-3061\n-9665\n-1182\n8675\n7405\n-6005\n-2464\n-9430\n-4185\n4444\n8324\n517\n17\n44\n929\n9352\n-2309\n-3430\n-7977\n-1768\n-730\n9203\n5129\n1012\n4534\n-6914\n-932\n-7334\n6670\n-1602\n-605\n9112\n2861\n4079\n-1554\n-3568\n8541\n4691\n-9503\n-5936\n-9503\n-468\n6627\n-8749\n-3549\n7537\n5089\n6148\n-1448\n5500\n5031\n3671\n7977\n2843\n-5233\n3898\n-5665\n3377\n-723\n9198\n-2570\n9457\n9235\n-7340\n3612\n1196\n-2354\n-886\n-910\n7260\n7765\n-642\n-1186\n1791\n-2025\n-2082\n309\n7561\n6901\n-2118\n-3552\n-6609\n-2435\n7328\n1600\n-8725\n7792\n-810\n1404\n822\n-4587\n-7045\n779\n6001\n-2431\n9431\n8515\n2294\n6155\n4279\n-9722\n-8905\n7319\n-3952\n-4089\n2622\n-4275\n9974\n5493\n-5231\n-8352\n-8601\n-7538\n-9093\n-8544\n-104\n-7304\n-4684\n9545\n-825\n-9675\n9776\n-5750\n-8282\n-3965\n3799\n-4688\n-5833\n702\n-8551\n-4177\n7913\n5298\n98\n2504\n1009\n4025\n-961\n-4192\n-9774\n8626\n-3696\n3153\n-7124\n-1239\n5722\n-5655\n-4293\n-1657\n-3793\n-4641\n-1318\n7098\n8185\n-9541\n3235\n3553\n3019\n-2619\n4054\n5765\n22\n2294\n-4585\n-4313\n-8916\n-8139\n4779\n5910\n-8844\n1169\n-4242\n-4244\n-3789\n-9423\n4131\n-4928\n-525\n2059\n-893\n-5252\n-8445\n6324\n4843\n8015\n4227\n-4346\n4550\n-6546\n2459\n5461\n7512\n-3439\n6190\n-2070\n-703\n-1015\n9909\n-7288\n-4783\n-636\n7667\n-9436\n-2114\n8180\n8042\n-9124\n-6061\n9879\n7481\n-2653\n6024\n-2560\n1069\n-4581\n-3747\n2288\n288\n-1239\n-2566\n-8764\n-8119\n5358\n-3910\n-7366\n7432\n-8998\n8674\n7982\n7155\n-205\n3926\n2238\n309\n9235\n-3899\n4876\n-8404\n-5385\n1019\n-1146\n-5272\n-7550\n1954\n-3917\n5653\n-160\n-4389\n-3538\n5612\n-6068\n-205\n4335\n-5056\n-399\n8015\n-897\n5031\n9723\n5681\n-5481\n-5695\n8486\n-4763\n8637\n8296\n8789\n-1319\n8262\n2013\n9623\n-3479\n9471\n-121\n-8479\n-9021\n9476\n6787\n6819\n-5845\n-5505\n-9309\n2465\n7635\n1231\n-1711\n-6245\n-9733\n-1142\n2768\n-5368\n-6740\n8913\n4452\n9774\n5275\n7165\n5542\n-1578\n3447\n-6808\n-9404\n-1032\n-5094\n255\n-1695\n-6100\n7412\n-5814\n-5669\n-2231\n-9964\n6983\n-1337\n-7021\n9461\n-4110\n741\n-8804\n-93\n-7781\n8053\n1927\n-1795\n-8889\n1985\n-8384\n5431\n-3589\n665\n4481\n-6411\n4660\n1463\n-8503\n3773\n8730\n4003\n9640\n-7208\n9204\n6707\n-2231\n-1382\n-5668\n3363\n-7837\n8035\n-1332\n7157\n9313\n-2420\n4324\n-8110\n-1003\n-1652\n5903\n3786\n6470\n6295\n1087\n-5731\n686\n3267\n-3673\n-8255\n-6325\n1611\n6462\n6762\n-8233\n9143\n-5900\n1036\n4188\n1912\n-6600\n-7522\n3073\n292\n-2152\n-323\n49\n-9005\n-26\n9110\n-3723\n1692\n2010\n296\n8688\n3075\n2534\n-1046\n3561\n7926\n2808\n-5271\n8592\n-7610\n9188\n-117\n9918\n2950\n8042\n-1931\n-3128\n-4100\n-6159\n6464\n7889\n-1624\n-6107\n791\n8072\n7661\n4653\n2389\n-9556\n6557\n-8384\n-8187\n5811\n6138\n8433\n154\n2240\n-5775\n-5452\n-9631\n9859\n2291\n1259\n490\n-2251\n6830\n4112\n-8651\n-4191\n-8018\n9016\n4867\n-9053\n-5442\n-4947\n5190\n-5816\n9245\n-4781\n7481\n4005\n3108\n3423\n-3691\n-5537\n-8136\n5389\n-134\n-321\n1077\n4087\n-3627\n2188\n989\n-2002\n859\n-3263\n547\n-5483\n-2050\n3765\n-9546\n-7725\n-4696\n-164\n-6116\n7056\n-5501\n289\n6029\n-3324\n7394\n-3166\n7751\n4725\n-5174\n9191\n3232\n-4817\n-6393\n6926\n-2566\n7396\n-5902\n-4971\n-8957\n-5224\n4204\n-4696\n3535\n-8068\n-8279\n-488\n1100\n5046\n7305\n2857\n-3903\n4141\n-2352\n2118\n-7393\n-693\n3268\n8530\n667\n-8828\n5085\n9317\n-6402\n-5827\n593\n6473\n-2858\n-9493\n-4622\n6985\n-1452\n-8398\n-3968\n-9144\n-6499\n-5334\n3735\n1822\n6640\n5330\n6492\n-9823\n2808\n-3394\n-6422\n-2028\n-5382\n8221\n7573\n7405\n-7263\n-3713\n-6173\n2117\n-3804\n-1980\n9326\n9431\n4192\n1231\n8660\n-1671\n4724\n-8003\n-1516\n-6409\n9955\n5893\n-2579\n7389\n7283\n2233\n4145\n9552\n9188\n-3459\n4257\n1554\n3282\n2699\n-800\n-990\n-2491\n-4375\n-220\n870\n-3637\n7086\n-7507\n3955\n-7861\n6880\n2211\n-7526\n-3700\n5052\n7113\n3272\n-3010\n-3450\n3827\n-66\n-1636\n-8503\n9856\n7784\n-1969\n-9162\n-2939\n4354\n276\n8762\n-7807\n-2956\n-7340\n843\n8031\n-6843\n1554\n-6181\n3102\n-3889\n6648\n-2842\n9457\n7376\n-2745\n-9114\n3220\n3117\n9129\n-7043\n8495\n-4637\n-2661\n-277\n3458\n4984\n-6108\n-9438\n9501\n791\n-7310\n-8233\n8085\n3962\n-9936\n6431\n55\n-4146\n3787\n9680\n-1315\n-7662\n2239\n-6744\n5912\n2481\n-3928\n-7829\n5901\n9509\n-2687\n7706\n-6849\n3142\n-7894\n1919\n886\n-6174\n-6921\n-8304\n-4092\n1197\n2141\n3703\n1709\n2512\n-3665\n-4804\n-9245\n-3899\n5571\n-2433\n3445\n-8060\n-8657\n2596\n-470\n-7249\n9742\n-9268\n8048\n-4020\n4218\n895\n2631\n1936\n-6168\n-9359\n-5679\n4012\n8567\n2188\n8817\n-4307\n-4635\n8252\n-1618\n-9677\n6225\n6517\n5122\n1254\n-881\n-6514\n6570\n-7099\n8816\n4907\n-526\n-5015\n-8925\n8910\n2739\n6006\n8983\n-8956\n-6616\n-114\n-22\n-8792\n4924\n-3604\n5972\n3742\n2590\n8335\n-5314\n-1743\n-4356\n7390\n7731\n9793\n3854\n-3883\n6710\n-6393\n-7738\n-703\n7376\n-2745\n-9021\n5642\n-9759\n9081\n-6837\n-3652\n5385\n-8812\n2817\n-2310\n8856\n-4659\n-329\n2602\n4380\n-7729\n6943\n-6681\n-3976\n-4359\n3213\n5016\n-2460\n6139\n-4859\n7279\n3473\n-4763\n-4373\n6650\n8656\n-2729\n-6630\n-4164\n-9892\n-3552\n-3623\n2481\n-8326\n755\n2373\n-4349\n5765\n6999\n7809\n-990\n2806\n2651\n-9480\n-3167\n3767\n-354\n-1112\n9694\n5828\n-706\n-5305\n1487\n-1295\n6689\n-588\n-1\n-4399\n-7842\n9224\n6820\n-6673\n-4046\n2301\n2443\n-80\n847\n-4545\n-3359\n-1181\n-9968\n2833\n324\n1335\n5804\n-2033\n-1461\n5446\n9452\n-6703\n-4879\n8098\n-3549\n4706\n8669\n7919\n-5386\n-2094\n6715\n8584\n-943\n9079\n-8783\n-8282\n8606\n342\n-944\n-7439\n6736\n-7491\n-179\n6474\n6725\n-3879\n1720\n8666\n-5483\n5581\n3286\n-7769\n6070\n4085\n8860\n4117\n4295\n6413\n-8118\n5759\n5864\n-6538\n9521\n-9232\n2389\n8007\n3878\n-3211\n1711\n2584\n3861\n-1975\n1025\n-4785\n-7948\n4688\n6969\n-4520\n4680\n567\n-4585\n-9492\n-5493\n-3166\n8472\n9757\n-8727\n296\n5960\n1384\n-9624\n-3155\n6531\n-7532\n4356\n9110\n-2446\n-9161\n1178\n-4488\n-2990\n-8248\n-506\n-2882\n-2335\n3694\n-7651\n-2658\n-9054\n-8489\n-3419\n-920\n-1928\n9864\n-3367\n85\n3484\n-1601\n5731\n-5481\n5597\n-703\n-3696\n-585\n3792\n252\n-5077\n411\n-821\n9377\n-7062\n-5673\n2586\n-5401\n2703\n907\n2138\n1879\n-555\n-6364\n8316\n8905\n-5237\n-6672\n3295\n6370\n-2176\n6169\n8368\n3732\n-3542\n1227\n-4257\n-4928\n2408\n-7977\n875\n2719\n-644\n-5930\n-4308\n-1580\n2664\n299\n2515\n5977\n-117\n-193\n5778\n-8083\n-1581\n7481\n2622\n9210\n-1006\n-1514\n-4648\n-2050\n-1599\n-7986\n-9244\n-9541\n1939\n4951\n66\n9588\n468\n-4806\n2335\n1677\n-7619\n-6758\n4764\n7834\n751\n-7549\n3073\n-2742\n-5951\n7448\n-6005\n6940\n-9706\n-9681\n1981\n-5086\n8995\n-6176\n7027\n-5546\n794\n7830\n5942\n-5514\n7754\n-124\n9977\n9510\n-1277\n8502\n-4536\n-8649\n5167\n-612\n-3401\n-7433\n-4092\n1023\n7411\n3573\n6762\n9961\n8818\n5932\n2119\n3479\n-2303\n2001\n-9410\n-2093\n-7880\n-9366\n6082\n2431\n1049\n5585\n7913\n3051\n-6349\n2993\n660\n-8950\n9064\n171\n1176\n-604\n6653\n3530\n-9004\n-7270\n9641\n6449\n-5028\n-7233\n-1729\n-2755\n1516\n4483\n4695\n-6236\n9001\n7328\n1383\n-7822\n3393\n-3786\n4583\n-5806\n9824\n5937\n9815\n685\n-706\n-5645\n-4696\n2852\n-5957\n7338\n8882\n-1472\n1437\n5226\n-555\n6860\n2618\n3887\n-2041\n8736\n-7738\n1197\n4704\n1169\n365\n1127\n-828\n2312\n-3555\n-2152\n-9902\n3246\n-7767\n6303\n8008\n-8198\n5635\n4692\n-5788\n1540\n-5140\n-957\n5193\n723\n-3889\n5306\n9975\n-6486\n1314\n-9411\n-6983\n-5505\n2489\n324\n-3437\n8655\n9670\n1554\n595\n1548\n-7265\n1513\n3530\n212\n-1496\n3435\n-7660\n2689\n-4356\n4706\n-8299\n4513\n932\n3521\n-3903\n4546\n-5275\n-7646\n-2304\n3123\n2991\n-817\n-1947\n8598\n639\n-3975\n3088\n7562\n-1904\n-1287\n7613\n-154\n8336\n-299\n5999\n5899\n9228\n6618\n9114\n-4815\n9752\n34\n519\n883\n-7567\n-3215\n-7702\n-3080\n6473\n-3107\n1214\n6491\n-8998\n2823\n-27\n-9188\n3358\n-4918\n6833\n8478\n-9008\n-8154\n1638\n7432\n-8936\n1393\n1529\n5949\n-598\n342\n-6381\n3807\n2756\n7951\n-8212\n2350\n-8369\n3997\n2030\n-6574\n61\n-9796\n-4872\n1568\n-6144\n1933\n-9669\n1332\n-309\n-1701\n6520\n-2337\n2165\n4001\n353\n2101\n-6081\n-8776\n4135\n-1399\n9961\n9696\n3765\n-8610\n-765\n-9137\n-6919\n8892\n-6087\n8455\n1899\n1229\n9955\n4161\n-2873\n1652\n4659\n-2687\n-7812\n-2202\n-8168\n4920\n-41\n8787\n4562\n-5583\n7289\n-9849\n5638\n-1436\n9315\n-432\n7153\n-2597\n7513\n5969\n1821\n3975\n-7866\n-7318\n-1794\n-6359\n-5267\n3141\n-6599\n-6382\n-4781\n-4453\n7772\n1971\n-9296\n-5570\n7737\n4137\n4151\n2584\n8301\n6327\n9074\n-5493\n-2464\n4993\n-6138\n2394\n7545\n-7419\n9008\n3377\n-321\n5390\n-974\n-9334\n-6538\n-1947\n5604\n5758\n2515\n9962\n-889\n-6248\n7615\n-3628\n-2794\n-8279\n-5753\n5716\n9979\n9364\n8795\n9894\n5042\n-9576\n5483\n3703\n4576\n-5904\n-5222\n3029\n-2356\n-4098\n5292\n986\n6271\n370\n-1389\n5883\n-9670\n8657\n3576\n1868\n5431\n7247\n3772\n-36\n684\n3282\n-6236\n-92\n-4695\n-1789\n-3484\n-3467\n3139\n658\n6490\n7430\n-4585\n-1239\n7052\n1131\n-1947\n-8756\n-8421\n-252\n-5984\n-8451\n-3098\n-1482\n8771\n8564\n4654\n-1767\n5985\n255\n-678\n-7957\n-2171\n9158\n-8027\n6149\n6752\n8053\n-8617\n-1559\n-3259\n1277\n-8671\n8180\n-5580\n3527\n-6781\n-6960\n-5655\n7943\n9447\n1802\n2360\n682\n2436\n5799\n3108\n-7263\n-3510\n6587\n-8733\n5030\n-3599\n-9283\n-6637\n7962\n-7950\n1779\n5484\n2999\n6451\n-5923\n-4571\n-2533\n-8167\n8658\n9588\n-9320\n-4455\n-7560\n-5096\n-154\n5577\n-7981\n4920\n-8710\n-472\n-8424\n-8353\n-7271\n-7681\n-4463\n1692\n3874\n2082\n9984\n-9438\n5947\n-8158\n4271\n5305\n-6826\n9723\n-9842\n-6800\n-6236\n-1483\n9205\n-9040\n6129\n-296\n-9643\n-4313\n3832\n-2647\n-3441\n6547\n-2930\n7953\n8455\n-7713\n-6541\n4098\n5608\n2896\n-1559\n-3532\n-2816\n-3282\n1919\n9118\n-8466\n-4199\n-4497\n-2659\n-584\n5581\n8633\n-428\n-1345\n4829\n-2741\n2550\n-1823\n7038\n1493\n1909\n6024\n-7270\n-8359\n7073\n-6359\n-9112\n2300\n7513\n4686\n4843\n-2711\n7741\n7377\n3772\n-5058\n6450\n-6541\n5918\n9491\n5005\n8046\n7205\n-1942\n-5477\n-5636\n9663\n2997\n4852\n-2537\n-5668\n-3038\n-2002\n-4081\n-3581\n2226\n-2032\n7327\n-8040\n-361\n-1466\n8345\n4020\n-2059\n-8114\n-8122\n445\n-5991\n7634\n-4251\n-3718\n-9788\n-8081\n-3743\n-2325\n2684\n8468\n-5106\n-5018\n-4497\n289\n-8128\n7386\n-2899\n-7340\n1384\n-214\n-5537\n-8783\n5997\n8614\n-8413\n-2882\n1034\n1363\n8853\n-6100\n118\n6100\n-9232\n-1881\n-2235\n197\n-9054\n-7549\n-6516\n-9864\n-1\n-7539\n4452\n-339\n5198\n8335\n-2858\n-2630\n5429\n6168\n-2044\n5901\n9510\n9719\n7784\n8568\n-2707\n-642\n8674\n603\n-625\n6041\n-601\n5036\n6257\n7338\n804\n4672\n1689\n1905\n-2483\n1649\n7139\n-1368\n2109\n9719\n944\n-5501\n-7701\n-7110\n-2466\n-9162\n-1472\n8979\n9188\n2181\n4789\n-5185\n-455\n-6927\n9685\n4116\n-2436\n-5715\n-5104\n-9142\n2076\n9918\n-7196\n-1895\n6583\n8874\n-2335\n-1228\n8789\n3858\n-4508\n7400\n388\n9156\n-9330\n-6675\n-8736\n-1645\n1183\n-1667\n-51\n-1013\n7042\n-1718\n3276\n2653\n-3819\n2854\n5454\n7224\n1854\n2291\n-2873\n2535\n-9228\n-1545\n-662\n-9436\n-3747\n9719\n-5545\n4893\n3982\n1144\n6101\n2985\n-8823\n3835\n-6834\n-7780\n-5518\n8927\n-2909\n-6479\n-5565\n-7957\n-7685\n-3888\n-5010\n6257\n6308\n-9042\n5858\n-8357\n-1461\n-3986\n-5156\n5974\n453\n2435\n6650\n817\n-4110\n-4141\n-8447\n5648\n8216\n-9536\n5554\n7021\n-583\n4807\n-7240\n-8148\n9791\n-5452\n8453\n-5741\n4133\n-2094\n-690\n8503\n-6393\n-4348\n-571\n1523\n3367\n7564\n-2118\n9232\n930\n1215\n3871\n8877\n427\n6226\n-368\n3988\n-1328\n9219\n7751\n4429\n-5807\n-2059\n559\n-5281\n3254\n2825\n-3316\n4324\n-4454\n6319\n9764\n8822\n989\n376\n-4009\n-167\n102\n9127\n-7318\n2307\n-1335\n-6919\n5860\n-2878\n-4170\n9677\n-3194\n1237\n6569\n3495\n-5505\n-7384\n3435\n-6881\n2839\n-4345\n-1673\n-6754\n2844\n-5314\n-641\n2623\n3961\n7220\n2771\n-8298\n6259\n3254\n3108\n9061\n2506\n5443\n-881\n4430\n827\n-88\n6143\n-2028\n-9240\n1545\n8611\n6960\n-5555\n-4115\n9251\n-2981\n4049\n5586\n1862\n9430\n521\n-1049\n-3264\n1923\n8042\n8135\n-6648\n-4094\n-4292\n-3211\n4377\n7207\n-9928\n-4605\n7809\n-1744\n8008\n-4857\n4395\n-1516\n-9723\n-5204\n-4571\n-2050\n8065\n-1847\n847\n5089\n-9108\n-3864\n3019\n4324\n8486\n-9159\n2825\n-1522\n955\n-2383\n533\n5998\n-8317\n6528\n-859\n2339\n-144\n8054\n-7271\n2075\n-4918\n74\n2211\n6782\n702\n-7986\n5038\n1104\n7713\n3445\n-6347\n7101\n1783\n-5324\n2149\n-9385\n1751\n-4454\n5908\n2667\n1556\n-7805\n-5587\n9555\n9952\n-7075\n6801\n-277\n-3404\n6534\n9585\n5571\n-5552\n8769\n-7166\n-4185\n4807\n7829\n-215\n-7456\n8371\n-4131\n4054\n3096\n6470\n1516\n-6090\n9717\n8658\n-9270\n895\n4852\n-4383\n7236\n-870\n-6563\n8723\n6775\n9682\n-1816\n-9378\n-3237\n-7610\n8032\n9400\n1631\n9510\n-1241\n1227\n3375\n2810\n3153\n-612\n-4089\n2253\n227\n-555\n-1477\n-2283\n7841\n2193\n2238\n3792\n5032\n-6211\n4422\n8342\n-2088\n4764\n3481\n9160\n-1706\n3981\n-4196\n7396\n9923\n-9493\n9110\n1912\n9687\n7305\n9682\n-8358\n5037\n-3531\n-5655\n-7961\n1180\n5746\n-8796\n8453\n-5459\n273\n9061\n-9537\n2019\n7244\n-5316\n5910\n3508\n-832\n-8860\n-6754\n-8700\n746\n5190\n8946\n6669\n6660\n-164\n794\n-8812\n-3581\n-1133\n-8761\n-8783\n4872\n3216\n-5324\n-5219\n-220\n-7017\n9435\n-1299\n-5146\n3825\n4924\n3250\n-8444\n-2669\n8008\n-5545\n6531\n9445\n3077\n8204\n-6511\n-7005\n4598\n-2139\n4416\n-6429\n3561\n9380\n1452\n-5447\n-5668\n-1233\n25\n-8597\n4986\n3130\n-5788\n1445\n7424\n2327\n8455\n-8087\n-2460\n-21\n1247\n8216\n2366\n1574\n2823\n-7981\n7297\n-6454\n5634\n2388\n5031\n6164\n9288\n5040\n9750\n-7013\n-5924\n-1140\n-2393\n7312\n-4622\n5918\n4087\n-5013\n-3296\n3174\n-3426\n1126\n-990\n-1112\n-4955\n-7451\n1702\n4764\n-3474\n-1631\n5778\n-1007\n-9807\n-8465\n-8164\n862\n-1490\n4842\n-6159\n-2619\n6174\n5627\n3090\n-2687\n-8169\n4208\n-3675\n-3182\n-1459\n2419\n-9617\n-9916\n2340\n-1341\n-1793\n1002\n5232\n-92\n7978\n313\n2049\n-1321\n-2729\n-7190\n-8671\n8379\n2582\n-4045\n8161\n4395\n-559\n-6530\n5493\n2294\n2141\n9364\n-6718\n4361\n-5749\n-3744\n6082\n-962\n8129\n9831\n-7866\n-9693\n-4100\n4867\n-4478\n-9891\n-1611\n-4213\n-3630\n-5941\n6717\n-9294\n992\n735\n9679\n-4303\n-5603\n930\n1330\n-4840\n7215\n-5929\n-7158\n-637\n2082\n-7807\n-9475\n9685\n4636\n-4848\n8936\n-8700\n-902\n-7075\n-4495\n-5944\n8356\n-5252\n6478\n-8108\n-7679\n7843\n1703\n-5377\n5432\n-5439\n-8699\n7329\n7645\n449\n4627\n7278\n8392\n-636\n-7194\n4169\n794\n-7866\n-399\n-9541\n-1788\n-3599\n6485\n1064\n-6592\n333\n-7729\n7756\n-6054\n5675\n5305\n5349\n3987\n-1559\n-5465\n295\n7557\n3555\n-324\n3479\n-1760\n-8717\n7908\n890\n-13\n6557\n9375\n-4979\n238\n-1538\n-5694\n5622\n-5548\n-2549\n-9685\n9181\n-1808\n-4604\n3358\n-9293\n7564\n9618\n-3476\n4867\n3144\n-7084\n9947\n4796\n5568\n-5110\n-9302\n-6676\n4909\n4642\n9990\n9894\n-3715\n-6292\n-4416\n2798\n-5673\n7321\n-2974\n355\n2771\n-6247\n686\n-8498\n3684\n7405\n-9492\n-4745\n7529\n5893\n-3326\n1919\n-242\n8652\n-7977\n-1116\n1868\n");
 	test!(day20_02_example1, 2, 1623178306, "1\n2\n-3\n3\n-2\n0\n4");
+
+	use day20::{CircularMixer, Decryptor};
+
+	#[test]
+	fn day20_mixer_example() {
+		let mut mixer = CircularMixer::new(vec![1, 2, -3, 3, -2, 0, 4]);
+		mixer.mix();
+		assert_eq!(vec![0, 3, -2, 1, 2, -3, 4], mixer.sequence());
+		assert_eq!((4, -3, 2), (mixer[1000], mixer[2000], mixer[3000]));
+	}
+
+	#[test]
+	fn day20_mixer_duplicates() {
+		// Both 1s move on their own, and moving by a multiple of `len - 1`
+		// brings a number back to where it was
+		let mut mixer = CircularMixer::new(vec![1, 1, 0, 3]);
+		mixer.mix();
+		assert_eq!(vec![0, 3, 1, 1], mixer.sequence());
+		let mut single = CircularMixer::new(vec![5]);
+		single.mix();
+		assert_eq!(vec![5], single.sequence());
+	}
+
+	#[test]
+	fn day20_decryptor_settings() {
+		let decrypted = Decryptor::new()
+			.offsets(&[1, 2, 7])
+			.decrypt("1\n2\n-3\n3\n-2\n0\n4")
+			.unwrap();
+		assert_eq!(&[0, 3, -2, 1, 2, -3, 4], decrypted.sequence());
+		assert_eq!(&[3, -2, 0], decrypted.coordinates());
+		assert_eq!(1, decrypted.grove_sum());
+
+		assert!(Decryptor::new().decrypt("1\nx\n0").is_err());
+		assert!(Decryptor::new().key(isize::MAX).decrypt("2\n0").is_err());
+		assert!(Decryptor::new().decrypt("").is_err());
+	}
 }